@@ -0,0 +1,59 @@
+//! # Scores benchmark
+//!
+//! Measure the time needed to analyze data and to compute the scores.
+//! Run with `cargo bench`.
+//! 
+
+use std::time::Instant;
+use libbytestat::Bytestat;
+
+fn random_data(size:usize) -> Vec<u8> {
+  let mut seed:u32 = 1;
+  (0..size).map(|_| {
+    seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+    (seed >> 16) as u8
+  }).collect()
+}
+
+fn bench<F: FnMut()>(name:&str, iterations:u32, mut f:F) {
+  let start = Instant::now();
+  for _ in 0..iterations {
+    f();
+  }
+  let elapsed = start.elapsed();
+  println!("{:<24} {:>12.3?} per iteration", name, elapsed / iterations);
+}
+
+fn main() {
+  let data = random_data(16 * 1024 * 1024);
+  let mut stats = Bytestat::new();
+
+  bench("analyze_slice 16 MiB", 4, || {
+    stats.reset();
+    stats.analyze_slice(&data);
+  });
+
+  bench("get_score every byte", 1, || {
+    let mut polled = Bytestat::new();
+    for x in &data[..65536] {
+      polled.analyze(*x);
+      std::hint::black_box(polled.get_score());
+    }
+  });
+
+  bench("get_score_non_zero every byte", 1, || {
+    let mut polled = Bytestat::new();
+    for x in &data[..65536] {
+      polled.analyze(*x);
+      std::hint::black_box(polled.get_score_non_zero());
+    }
+  });
+
+  bench("get_score_unique", 10000, || {
+    std::hint::black_box(stats.get_score_unique());
+  });
+
+  bench("get_scores", 1000, || {
+    std::hint::black_box(stats.get_scores());
+  });
+}
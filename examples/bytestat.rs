@@ -8,32 +8,20 @@
 //! Example: ~68% is a bad score, but there is not enough data for the method to be precise.
 //! 
 
-use std::{io::Read};
 use libbytestat::Bytestat;
 
 fn main() {
 
   let mut stats = Bytestat::new();
-  let mut counter:u128 = 0;
-  let percent = 256 * 4096;
 
-  for x in std::io::stdin().bytes() {
-    match x {
-        Ok(data) => {
-          stats.analyze(data);
-          counter += 1;
-        },
-        Err(err) => {
-          eprintln!("{:?}", err);
-        }
-    }
+  if let Err(err) = stats.analyze_reader(std::io::stdin().lock()) {
+    eprintln!("{}", err);
   }
 
   println!("\nRAW SCORES AS STRING");
   println!("{}", stats.get_scores_string("\n"));
 
-  println!("\nFINAL SCORE");
-  println!("{} samples", counter );
-  println!("{}{:.0}%", if counter < (percent * 100) {"~"} else {""}, stats.get_score() );
+  println!("\nREPORT");
+  print!("{}", stats);
 
 }
\ No newline at end of file
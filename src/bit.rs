@@ -0,0 +1,247 @@
+//! # Bit analysis
+//!
+//! Analysis of a stream of bits.
+
+use crate::{Scores, Weights};
+
+// Number of interval slots, intervals of INTERVALS - 1 bits or more are all recorded as INTERVALS - 1.
+const INTERVALS:usize = 64;
+
+// Number of significant intervals giving the interval scores 1.0.
+// Random bits repeat after x bits with a probability of 2^-x, an interval of x bits is expected 2^(12-x) times
+// the significance threshold: the 11 first intervals are significant, but the 9th to 11th only by a factor 8 or less.
+// Capping at 8, 16 times the threshold, keeps the scores of random bits at 1.0 despite their fluctuations.
+const INTERVAL_CAP:usize = 8;
+
+/// Running analysis of a stream of bits.
+///
+/// The five scores of `Bytestat` are computed over the 2 possible bit values instead of the 256 byte values,
+/// to find a bias hidden by the framing of the bits in bytes.
+/// The interval is the number of bits since the previous occurrence of the same bit value.
+/// An interval is significant when it was recorded more than (bits count) / 4096 times,
+/// the interval scores reach 1.0 once 8 consecutive intervals are significant.
+/// With only 2 values, the uniqueness score is 1.0 when the counts of 0 and 1 differ, 0.0 when they are equal.
+///
+/// Bits are given one at a time by `analyze`, the bytes given to `analyze_byte` and `analyze_slice`
+/// are split most significant bit first. For a source producing the least significant bit first,
+/// analyze `value.reverse_bits()` instead.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::BitStat;
+/// let mut balanced = BitStat::new();
+/// let mut biased = BitStat::new();
+/// # let mut seed:u32 = 1;
+/// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+/// for x in 0..10000 {
+///   let value = get_random_byte();
+///   balanced.analyze_byte( value );
+///   // About 3 bits out of 4 are set.
+///   biased.analyze_byte( value | get_random_byte() );
+/// }
+///
+/// assert_eq!(balanced.get_counter(), 80000);
+/// assert!(balanced.get_score_amplitude() > 0.99);
+/// assert!(biased.get_score_amplitude() < 0.6);
+/// assert!(biased.get_score() < balanced.get_score());
+/// ```
+#[derive(Clone)]
+pub struct BitStat {
+    counter:u128,
+    dist:[u128;2],
+    interval:[u128;INTERVALS],
+    last:[u128;2],
+    weights:Weights,
+}
+
+impl BitStat {
+  /// Create new BitStat object.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::BitStat;
+  /// let stats = BitStat::new();
+  /// ```
+  pub fn new() -> BitStat {
+    BitStat {
+      counter:0,
+      dist:[0;2],
+      interval:[0;INTERVALS],
+      last:[0;2],
+      weights:Weights::default(),
+    }
+  }
+
+  /// Analyze one bit, bits must be analysed in sequence.
+  ///
+  /// # Arguments
+  ///
+  /// * `bit` - A bit to be analyzed, bool
+  pub fn analyze(&mut self, bit:bool) {
+    let value = bit as usize;
+    self.counter += 1;
+    let last = self.last[value];
+    if last > 0 {
+      let gap = self.counter - last;
+      let slot = if gap >= INTERVALS as u128 { INTERVALS - 1 } else { gap as usize };
+      self.interval[slot] += 1;
+    }
+    self.dist[value] += 1;
+    self.last[value] = self.counter;
+  }
+
+  /// Analyze the 8 bits of a byte, most significant bit first.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - A byte whose bits are analyzed, u8
+  pub fn analyze_byte(&mut self, value:u8) {
+    for bit in (0..8).rev() {
+      self.analyze(value >> bit & 1 == 1);
+    }
+  }
+
+  /// Analyze the bits of a slice of bytes, in order, most significant bit first.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes whose bits are analyzed, &[u8]
+  pub fn analyze_slice(&mut self, data:&[u8]) {
+    for value in data {
+      self.analyze_byte(*value);
+    }
+  }
+
+  /// Get the number of bits analyzed.
+  pub fn get_counter(&self) -> u128 {
+    self.counter
+  }
+
+  /// Generate the score based on the bit values being present in the set.
+  ///
+  /// (bit values present in set) / 2
+  pub fn get_score_non_zero(&self) -> f64 {
+    self.get_scores().non_zero
+  }
+
+  /// Generate the score based on the uniqueness of the bits distribution.
+  ///
+  /// (bit values with a count shared by no other value) / 2
+  pub fn get_score_unique(&self) -> f64 {
+    self.get_scores().unique
+  }
+
+  /// Generate the score based on the amplitude of the bits distribution, how balanced the bits are.
+  ///
+  /// 1 - (standard deviation of the counts) / (mean count), at least 0.0
+  pub fn get_score_amplitude(&self) -> f64 {
+    self.get_scores().amplitude
+  }
+
+  /// Generate the score based on the continuity of significant intervals.
+  ///
+  /// (significant intervals, up to 8) / 8
+  pub fn get_score_interval_continuity(&self) -> f64 {
+    self.get_scores().interval_continuity
+  }
+
+  /// Generate the score based on the amplitude of significant intervals.
+  ///
+  /// (largest significant interval, up to 8) / 8
+  pub fn get_score_interval_amplitude(&self) -> f64 {
+    self.get_scores().interval_amplitude
+  }
+
+  /// Generate the final score, between 0 and 100, weighted like the final score of `Bytestat`.
+  pub fn get_score(&self) -> f64 {
+    self.get_scores().total
+  }
+
+  /// Generate all the scores at once, as named fields.
+  pub fn get_scores(&self) -> Scores {
+    let mut scores = Scores {
+      non_zero:0.0,
+      unique:0.0,
+      amplitude:0.0,
+      interval_continuity:0.0,
+      interval_amplitude:0.0,
+      total:0.0,
+    };
+
+    if self.counter == 0 {
+      return scores
+    }
+
+    //1 of 5
+    scores.non_zero = self.dist.iter().filter(|x| **x > 0).count() as f64 / 2.0;
+
+    //2 of 5
+    scores.unique = if self.dist[0] != self.dist[1] { 1.0 } else { 0.0 };
+
+    //3 of 5
+    let squares = self.dist[0] * self.dist[0] + self.dist[1] * self.dist[1];
+    let variation = crate::sqrt((2 * squares - self.counter * self.counter) as f64) / self.counter as f64;
+    scores.amplitude = if variation < 1.0 { 1.0 - variation } else { 0.0 };
+
+    //4 of 5
+    let threshold = self.counter / 4096;
+    let mut interval_max = 0;
+    let mut significant = 0;
+    for x in 1..INTERVALS {
+      if self.interval[x] > threshold {
+        interval_max = x;
+        significant += 1;
+      }
+    }
+    scores.interval_continuity = significant.min(INTERVAL_CAP) as f64 / INTERVAL_CAP as f64;
+
+    //5 of 5
+    scores.interval_amplitude = interval_max.min(INTERVAL_CAP) as f64 / INTERVAL_CAP as f64;
+
+    //FINAL SCORE
+    scores.total = scores.non_zero * self.weights.non_zero;
+    scores.total += scores.unique * self.weights.unique;
+    scores.total += scores.amplitude * self.weights.amplitude;
+    scores.total += scores.interval_continuity * self.weights.interval_continuity;
+    scores.total += scores.interval_amplitude * self.weights.interval_amplitude;
+
+    scores
+  }
+
+  /// Generate all the scores at once, in the order of the fields of `Scores`.
+  pub fn get_scores_array(&self) -> [f64;6] {
+    let scores = self.get_scores();
+    [
+      scores.non_zero,
+      scores.unique,
+      scores.amplitude,
+      scores.interval_continuity,
+      scores.interval_amplitude,
+      scores.total
+    ]
+  }
+}
+
+impl Default for BitStat {
+  fn default() -> BitStat {
+    BitStat::new()
+  }
+}
+
+/// Show the number of bits analyzed and the scores.
+impl core::fmt::Debug for BitStat {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let scores = self.get_scores();
+    f.debug_struct("BitStat")
+      .field("counter", &self.counter)
+      .field("score_non_zero", &scores.non_zero)
+      .field("score_unique", &scores.unique)
+      .field("score_amplitude", &scores.amplitude)
+      .field("score_interval_continuity", &scores.interval_continuity)
+      .field("score_interval_amplitude", &scores.interval_amplitude)
+      .field("score", &scores.total)
+      .finish()
+  }
+}
@@ -0,0 +1,147 @@
+//! # Builder
+//!
+//! Configuration of a new analysis, including the scores counted by the final score.
+
+use crate::Bytestat;
+
+/// Builder of a configured `Bytestat`, see `Bytestat::builder`.
+///
+/// Each of the five scores can be disabled. A disabled score is not computed and reports 0.0,
+/// the final score only counts the enabled scores, with their weights scaled to sum to 100.
+/// The final score stays between 0 and 100, it is 0 when every score is disabled.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut distribution = Bytestat::builder()
+///   .interval_continuity( false )
+///   .interval_amplitude( false )
+///   .build();
+/// let mut stats = Bytestat::new();
+/// # let mut seed:u32 = 1;
+/// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+/// let data:Vec<u8> = (0..1000).map(|_| get_random_byte()).collect();
+/// distribution.analyze_slice( &data );
+/// stats.analyze_slice( &data );
+///
+/// let scores = distribution.get_scores();
+/// assert_eq!(scores.interval_continuity, 0.0);
+/// assert_eq!(scores.interval_amplitude, 0.0);
+/// assert_eq!(scores.non_zero, stats.get_score_non_zero());
+/// let expected = (scores.non_zero + scores.unique + scores.amplitude) * 100.0 / 3.0;
+/// assert!((scores.total - expected).abs() < 1e-9);
+/// assert!(scores.total >= 0.0 && scores.total <= 100.0);
+///
+/// let mut constant = Bytestat::builder().amplitude( false ).build();
+/// constant.analyze_slice( &[0u8;1000] );
+/// assert!(constant.get_score() < 1.0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BytestatBuilder {
+    enabled:[bool;5],
+    significance_divisor:u128,
+    #[cfg(feature = "alloc")]
+    max_lag:usize,
+    #[cfg(feature = "alloc")]
+    sample_size:usize,
+    #[cfg(feature = "alloc")]
+    maurer_block_bits:u8,
+}
+
+impl BytestatBuilder {
+  /// Create a new builder, with every score enabled and the default configuration of `Bytestat::new()`.
+  pub fn new() -> BytestatBuilder {
+    BytestatBuilder {
+      enabled:[true;5],
+      significance_divisor:4096,
+      #[cfg(feature = "alloc")]
+      max_lag:0,
+      #[cfg(feature = "alloc")]
+      sample_size:0,
+      #[cfg(feature = "alloc")]
+      maurer_block_bits:0,
+    }
+  }
+
+  /// Enable or disable the score based on the bytes present in the set, see `Bytestat::get_score_non_zero`.
+  pub fn non_zero(mut self, enabled:bool) -> BytestatBuilder {
+    self.enabled[0] = enabled;
+    self
+  }
+
+  /// Enable or disable the score based on the uniqueness of the bytes distribution, see `Bytestat::get_score_unique`.
+  pub fn unique(mut self, enabled:bool) -> BytestatBuilder {
+    self.enabled[1] = enabled;
+    self
+  }
+
+  /// Enable or disable the score based on the amplitude of the bytes distribution, see `Bytestat::get_score_amplitude`.
+  pub fn amplitude(mut self, enabled:bool) -> BytestatBuilder {
+    self.enabled[2] = enabled;
+    self
+  }
+
+  /// Enable or disable the score based on the continuity of significant intervals,
+  /// see `Bytestat::get_score_interval_continuity`.
+  pub fn interval_continuity(mut self, enabled:bool) -> BytestatBuilder {
+    self.enabled[3] = enabled;
+    self
+  }
+
+  /// Enable or disable the score based on the amplitude of significant intervals,
+  /// see `Bytestat::get_score_interval_amplitude`.
+  pub fn interval_amplitude(mut self, enabled:bool) -> BytestatBuilder {
+    self.enabled[4] = enabled;
+    self
+  }
+
+  /// Set the significance divisor, see `Bytestat::set_significance_divisor`.
+  pub fn significance_divisor(mut self, divisor:u128) -> BytestatBuilder {
+    self.significance_divisor = divisor;
+    self
+  }
+
+  /// Set the largest lag of the autocorrelation, with the `alloc` feature, see `Bytestat::with_max_lag`.
+  #[cfg(feature = "alloc")]
+  pub fn max_lag(mut self, max_lag:usize) -> BytestatBuilder {
+    self.max_lag = max_lag;
+    self
+  }
+
+  /// Set the number of bytes kept in the sample, with the `alloc` feature, see `Bytestat::with_sample_size`.
+  #[cfg(feature = "alloc")]
+  pub fn sample_size(mut self, sample_size:usize) -> BytestatBuilder {
+    self.sample_size = sample_size;
+    self
+  }
+
+  /// Set the block size of Maurer's universal test, with the `alloc` feature, see `Bytestat::with_maurer_block_bits`.
+  #[cfg(feature = "alloc")]
+  pub fn maurer_block_bits(mut self, block_bits:u8) -> BytestatBuilder {
+    self.maurer_block_bits = block_bits;
+    self
+  }
+
+  /// Create the configured Bytestat object.
+  pub fn build(self) -> Bytestat {
+    #[cfg(feature = "alloc")]
+    let mut stats = {
+      let mut stats = Bytestat::with_sample_size(self.sample_size);
+      stats.lags = crate::lag::LagTracker::new(self.max_lag);
+      stats.maurer = crate::maurer::MaurerTracker::new(self.maurer_block_bits.min(16));
+      stats
+    };
+    #[cfg(not(feature = "alloc"))]
+    let mut stats = Bytestat::new();
+    stats.set_significance_divisor(self.significance_divisor);
+    stats.enabled = self.enabled;
+    stats
+  }
+}
+
+impl Default for BytestatBuilder {
+  fn default() -> BytestatBuilder {
+    BytestatBuilder::new()
+  }
+}
@@ -0,0 +1,137 @@
+//! # Lagged products
+//!
+//! Running sums of the products of bytes separated by a fixed lag, used by the autocorrelation.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use crate::SnapshotError;
+use crate::snapshot::{SnapshotReader, SnapshotWriter};
+
+// For each lag from 1 to `max_lag`, the sum of the products of the bytes separated by that lag.
+// The first and last `max_lag` bytes are kept, so two consecutive trackers can be merged exactly.
+#[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct LagTracker {
+    max_lag:usize,
+    head:Vec<u8>,
+    tail:VecDeque<u8>,
+    products:Vec<u128>,
+}
+
+impl LagTracker {
+  pub(crate) fn new(max_lag:usize) -> LagTracker {
+    LagTracker {
+      max_lag,
+      head:Vec::with_capacity(max_lag),
+      tail:VecDeque::with_capacity(max_lag + 1),
+      products:vec![0;max_lag],
+    }
+  }
+
+  pub(crate) fn max_lag(&self) -> usize {
+    self.max_lag
+  }
+
+  pub(crate) fn reset(&mut self) {
+    self.head.clear();
+    self.tail.clear();
+    self.products.fill(0);
+  }
+
+  pub(crate) fn push(&mut self, value:u8) {
+    if self.max_lag == 0 {
+      return
+    }
+    let len = self.tail.len();
+    for lag in 1..=len {
+      self.products[lag - 1] += self.tail[len - lag] as u128 * value as u128;
+    }
+    self.tail.push_back(value);
+    if self.tail.len() > self.max_lag {
+      self.tail.pop_front();
+    }
+    if self.head.len() < self.max_lag {
+      self.head.push(value);
+    }
+  }
+
+  // Push `value` `count` times: once the kept bytes are all `value`, each push adds value * value to every sum.
+  pub(crate) fn push_repeated(&mut self, value:u8, count:u128) {
+    let pushed = count.min(self.max_lag as u128);
+    for _ in 0..pushed {
+      self.push(value);
+    }
+    for product in self.products.iter_mut() {
+      *product += (count - pushed) * value as u128 * value as u128;
+    }
+  }
+
+  // Append the products of `other`, tracking the bytes following the ones of `self`.
+  // Lags larger than the maximum lag of `other` are no longer tracked.
+  pub(crate) fn merge(&mut self, other:&LagTracker) {
+    if other.max_lag < self.max_lag {
+      self.max_lag = other.max_lag;
+      self.head.truncate(self.max_lag);
+      self.products.truncate(self.max_lag);
+      while self.tail.len() > self.max_lag {
+        self.tail.pop_front();
+      }
+    }
+    let len = self.tail.len();
+    for lag in 1..=self.max_lag {
+      self.products[lag - 1] += other.products[lag - 1];
+      for before in 1..=lag.min(len) {
+        if let Some(after) = other.head.get(lag - before) {
+          self.products[lag - 1] += self.tail[len - before] as u128 * *after as u128;
+        }
+      }
+    }
+    for value in other.head.iter().take(self.max_lag - self.head.len()) {
+      self.head.push(*value);
+    }
+    for value in other.tail.iter() {
+      self.tail.push_back(*value);
+      if self.tail.len() > self.max_lag {
+        self.tail.pop_front();
+      }
+    }
+  }
+
+  pub(crate) fn product(&self, lag:usize) -> u128 {
+    self.products[lag - 1]
+  }
+
+  // The maximum lag, the first bytes, the last bytes and the sums of the products.
+  pub(crate) fn write_snapshot(&self, snapshot:&mut SnapshotWriter) {
+    snapshot.len(self.max_lag);
+    snapshot.len(self.head.len());
+    snapshot.bytes(&self.head);
+    snapshot.len(self.tail.len());
+    for value in &self.tail {
+      snapshot.u8(*value);
+    }
+    for product in &self.products {
+      snapshot.u128(*product);
+    }
+  }
+
+  pub(crate) fn read_snapshot(snapshot:&mut SnapshotReader<'_>) -> Result<LagTracker, SnapshotError> {
+    let max_lag = snapshot.len(16)?;
+    let len = snapshot.len(1)?;
+    let head = snapshot.bytes(len)?.to_vec();
+    let len = snapshot.len(1)?;
+    let tail:VecDeque<u8> = snapshot.bytes(len)?.iter().copied().collect();
+    if head.len() > max_lag || tail.len() > max_lag || tail.len() < head.len() {
+      return Err(SnapshotError::Corrupted)
+    }
+    let mut products = Vec::with_capacity(max_lag);
+    for _ in 0..max_lag {
+      products.push(snapshot.u128()?);
+    }
+    Ok(LagTracker {
+      max_lag,
+      head,
+      tail,
+      products,
+    })
+  }
+}
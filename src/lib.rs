@@ -5,324 +5,5278 @@
 //! The distribution and interval of each byte is measured. 
 //! Five metrics are used to measure different aspects of the set. 
 //! The final score is between 0 and 100 as f64. 
-//! Good quality random data should score 100 when rounded up.
+//! Good quality random data should score 100 when rounded up, see `Bytestat::get_score_rounded`.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! The `alloc` feature keeps the interval table on the heap and provides the methods returning a `String`.
+//! Without `std`, the `libm` feature is needed for the floating point functions.
+//! The reader and writer helpers need `std`.
+//! The `serde` feature adds serialization and JSON output, the `rayon` feature adds parallel analysis.
+//! The `flate2` feature adds the compression ratio, the `base64` feature decodes base64 input.
+//! The `wasm` feature adds JavaScript bindings, to build for `wasm32-unknown-unknown`.
+//! The `test-setters` feature is only meant for the tests of the crate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::BTreeMap, format, string::String, vec, vec::Vec};
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("libbytestat needs either the `std` or the `libm` feature");
+
+#[cfg(feature = "alloc")]
+type CountTable = Box<[u32]>;
+
+#[cfg(not(feature = "alloc"))]
+type CountTable = [u32;256*256];
+
+#[cfg(feature = "alloc")]
+type ByteCounts = Box<[u128]>;
+
+#[cfg(not(feature = "alloc"))]
+type ByteCounts = [u128;256];
+
+#[cfg(feature = "alloc")]
+type Probabilities = Box<[f64]>;
+
+#[cfg(not(feature = "alloc"))]
+type Probabilities = [f64;256];
+
+/// Function called by the progress hook, with the `alloc` feature, see `Bytestat::set_progress_hook`.
+#[cfg(feature = "alloc")]
+pub type ProgressFn = Box<dyn FnMut(u128, &Bytestat) + Send + Sync>;
+
+// The progress hook and the number of bytes between two calls, a clone of the object has no hook.
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+struct ProgressHook(Option<(u128, ProgressFn)>);
+
+#[cfg(feature = "alloc")]
+impl Clone for ProgressHook {
+  fn clone(&self) -> ProgressHook {
+    ProgressHook(None)
+  }
+}
+
+/// All the scores of an analysis.
+/// See the matching `get_score*` methods of `Bytestat` for their meaning.
+/// With the `serde` feature, the final score is serialized as `score`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scores {
+    pub non_zero:f64,
+    pub unique:f64,
+    pub amplitude:f64,
+    pub interval_continuity:f64,
+    pub interval_amplitude:f64,
+    #[cfg_attr(feature = "serde", serde(rename = "score"))]
+    pub total:f64,
+}
+
+/// Interpretation of the final score, see `Bytestat::get_confidence`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Confidence {
+    /// Not enough bytes were analyzed for the score to be precise, see `Bytestat::is_significant`.
+    Insufficient,
+    /// The final score rounds up to 98 or lower, the data is very problematic.
+    Poor,
+    /// The final score rounds up to 99: the data is problematic, but only one point short of `Good`.
+    /// A single weak metric can cause it, analyzing more data tells a small defect from bad luck.
+    Marginal,
+    /// The final score rounds up to 100, as expected from good quality random data.
+    Good,
+}
+
+/// Final result of an analysis, without the tables of the analysis, see `Bytestat::into_report`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Report {
+    /// The number of bytes analyzed.
+    pub counter:u128,
+    /// All the scores.
+    pub scores:Scores,
+    /// The interpretation of the final score.
+    pub confidence:Confidence,
+}
+
+/// Weight of each score in the final score.
+/// The weights should sum to 100, for the final score to stay between 0 and 100.
+/// The default weight of each score is 20.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Weights {
+    pub non_zero:f64,
+    pub unique:f64,
+    pub amplitude:f64,
+    pub interval_continuity:f64,
+    pub interval_amplitude:f64,
+}
+
+impl Default for Weights {
+  fn default() -> Weights {
+    Weights {
+      non_zero:20.0,
+      unique:20.0,
+      amplitude:20.0,
+      interval_continuity:20.0,
+      interval_amplitude:20.0,
+    }
+  }
+}
+
+#[cfg(feature = "alloc")]
+mod rolling;
+
+#[cfg(feature = "alloc")]
+pub use rolling::RollingBytestat;
+
+#[cfg(feature = "alloc")]
+mod lag;
+
+#[cfg(feature = "alloc")]
+mod maurer;
+
+#[cfg(feature = "alloc")]
+mod snapshot;
+
+mod builder;
+
+pub use builder::BytestatBuilder;
+
+#[cfg(feature = "alloc")]
+mod word;
+
+#[cfg(feature = "alloc")]
+pub use word::WordStat;
+
+mod bit;
+
+pub use bit::BitStat;
+
+#[cfg(feature = "std")]
+mod sync;
+
+#[cfg(feature = "std")]
+pub use sync::SyncBytestat;
+
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "wasm")]
+pub use wasm::WasmBytestat;
+
+/// Error returned when a hexadecimal string can not be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// The string contains an odd number of hexadecimal digits.
+    OddLength,
+    /// The string contains a character that is neither a hexadecimal digit nor whitespace between two bytes.
+    InvalidDigit(char),
+}
+
+impl core::fmt::Display for HexError {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      HexError::OddLength => write!(f, "odd number of hexadecimal digits"),
+      HexError::InvalidDigit(c) => write!(f, "invalid hexadecimal digit {:?}", c)
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexError {}
+
+/// Error returned when a base64 string can not be decoded, with the `base64` feature.
+#[cfg(feature = "base64")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The number of characters can not be made of groups of 4, or the padding is too long.
+    InvalidLength,
+    /// The string contains a character that is neither in the standard base64 alphabet nor whitespace,
+    /// or a character after the padding.
+    InvalidCharacter(char),
+}
+
+#[cfg(feature = "base64")]
+impl core::fmt::Display for Base64Error {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Base64Error::InvalidLength => write!(f, "invalid base64 length"),
+      Base64Error::InvalidCharacter(c) => write!(f, "invalid base64 character {:?}", c)
+    }
+  }
+}
+
+#[cfg(all(feature = "base64", feature = "std"))]
+impl std::error::Error for Base64Error {}
+
+/// Error returned when a binary snapshot can not be loaded, with the `alloc` feature, see `Bytestat::from_bytes`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The snapshot was written by an unknown version of the layout.
+    UnsupportedVersion(u8),
+    /// The snapshot ends before the last field, or a length goes past its end.
+    Truncated,
+    /// The snapshot goes on after the last field.
+    TrailingBytes,
+    /// A field holds an impossible value, or the counts are not consistent with each other.
+    Corrupted,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for SnapshotError {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      SnapshotError::UnsupportedVersion(version) => write!(f, "unsupported snapshot version {}", version),
+      SnapshotError::Truncated => write!(f, "truncated snapshot"),
+      SnapshotError::TrailingBytes => write!(f, "bytes after the end of the snapshot"),
+      SnapshotError::Corrupted => write!(f, "corrupted snapshot")
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+/// Error returned by the fallible methods of `Bytestat`.
+/// Analyzing bytes never fails, only reading, decoding or configuring can.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use libbytestat::{Bytestat, BytestatError, HexError, Weights};
+/// fn analyze_all(hex:&str, weights:Weights, reader:impl std::io::Read) -> Result<Bytestat, BytestatError> {
+///   let mut stats = Bytestat::with_weights( weights )?;
+///   stats.analyze_hex( hex )?;
+///   stats.analyze_reader( reader )?;
+///   Ok(stats)
+/// }
+///
+/// struct Broken;
+/// impl std::io::Read for Broken {
+///   fn read(&mut self, _:&mut [u8]) -> std::io::Result<usize> {
+///     Err(std::io::Error::new(std::io::ErrorKind::Other, "disconnected"))
+///   }
+/// }
+///
+/// let weights = Weights::default();
+/// let stats = analyze_all( "cafe", weights, &b"babe"[..] ).unwrap();
+/// assert_eq!(stats.get_counter(), 6);
+///
+/// let err = analyze_all( "caf", weights, &b""[..] ).unwrap_err();
+/// assert_eq!(err.to_string(), "invalid hexadecimal string: odd number of hexadecimal digits");
+/// let err = analyze_all( "cafe", Weights { non_zero:0.0, ..weights }, &b""[..] ).unwrap_err();
+/// assert_eq!(err.to_string(), "weights must be finite, positive or zero, and sum to 100");
+/// let err = analyze_all( "cafe", weights, Broken ).unwrap_err();
+/// assert_eq!(err.to_string(), "read error: disconnected");
+/// assert!(std::error::Error::source(&err).is_some());
+/// assert!(matches!(BytestatError::from(HexError::InvalidDigit('g')), BytestatError::InvalidHex(_)));
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum BytestatError {
+    /// The reader returned an error, with the `std` feature.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// The hexadecimal string can not be decoded.
+    InvalidHex(HexError),
+    /// The base64 string can not be decoded, with the `base64` feature.
+    #[cfg(feature = "base64")]
+    InvalidBase64(Base64Error),
+    /// The binary snapshot can not be loaded, with the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    InvalidSnapshot(SnapshotError),
+    /// A weight is negative or not finite, or the weights do not sum to 100.
+    InvalidWeights,
+    /// An expected probability is negative or not finite, or the probabilities do not sum to 1.
+    InvalidDistribution,
+}
+
+impl core::fmt::Display for BytestatError {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      #[cfg(feature = "std")]
+      BytestatError::Io(err) => write!(f, "read error: {}", err),
+      BytestatError::InvalidHex(err) => write!(f, "invalid hexadecimal string: {}", err),
+      #[cfg(feature = "base64")]
+      BytestatError::InvalidBase64(err) => write!(f, "invalid base64 string: {}", err),
+      #[cfg(feature = "alloc")]
+      BytestatError::InvalidSnapshot(err) => write!(f, "invalid snapshot: {}", err),
+      BytestatError::InvalidWeights => write!(f, "weights must be finite, positive or zero, and sum to 100"),
+      BytestatError::InvalidDistribution => write!(f, "probabilities must be finite, positive or zero, and sum to 1")
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BytestatError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      BytestatError::Io(err) => Some(err),
+      BytestatError::InvalidHex(err) => Some(err),
+      #[cfg(feature = "base64")]
+      BytestatError::InvalidBase64(err) => Some(err),
+      BytestatError::InvalidSnapshot(err) => Some(err),
+      BytestatError::InvalidWeights | BytestatError::InvalidDistribution => None
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for BytestatError {
+  fn from(err:std::io::Error) -> BytestatError {
+    BytestatError::Io(err)
+  }
+}
+
+impl From<HexError> for BytestatError {
+  fn from(err:HexError) -> BytestatError {
+    BytestatError::InvalidHex(err)
+  }
+}
+
+#[cfg(feature = "base64")]
+impl From<Base64Error> for BytestatError {
+  fn from(err:Base64Error) -> BytestatError {
+    BytestatError::InvalidBase64(err)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl From<SnapshotError> for BytestatError {
+  fn from(err:SnapshotError) -> BytestatError {
+    BytestatError::InvalidSnapshot(err)
+  }
+}
+
+/// Running analysis of a sequence of bytes.
+///
+/// The interval and byte pair tables are allocated on the heap, so the object itself stays small.
+/// Each interval and byte pair count saturates at u32::MAX instead of wrapping around.
+/// The other counts are u128 and the scores square them, an analysis holds up to 2^60 bytes:
+/// they take over a year at 30 GB/s, only `analyze_repeated` and `merge` can reach that many bytes sooner.
+/// The last position of each byte value is never after the byte counter, `reset` clears both together,
+/// so the interval since the previous occurrence can not underflow.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use libbytestat::Bytestat;
+/// let mut stats = Bytestat::new();
+/// stats.analyze_slice( &[0, 0] );
+/// for x in 0..33 {
+///   stats.merge( &stats.clone() );
+/// }
+///
+/// assert_eq!(stats.get_counter(), 1 << 34);
+/// assert_eq!(stats.get_digraph_counts()[0], u32::MAX as u128);
+/// assert_eq!(stats.get_distribution()[0], 1 << 34);
+/// # }
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use libbytestat::Bytestat;
+/// assert!(std::mem::size_of::<Bytestat>() < 16 * 1024);
+/// # }
+/// ```
+///
+/// Cloning copies the whole analysis, the clone and the original can then be fed independently.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut original = Bytestat::new();
+/// let mut reference = Bytestat::new();
+/// let ramp:Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+/// original.analyze_slice( b"shared beginning of the stream" );
+/// reference.analyze_slice( b"shared beginning of the stream" );
+/// 
+/// let mut fork = original.clone();
+/// original.analyze_slice( &[0u8;4096] );
+/// fork.analyze_slice( &ramp );
+/// reference.analyze_slice( &ramp );
+/// 
+/// assert_ne!(original.get_score(), fork.get_score());
+/// assert_eq!(reference.get_score(), fork.get_score());
+/// ```
+///
+/// With the `serde` feature, the analysis can be serialized and resumed later.
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use libbytestat::Bytestat;
+/// # let mut seed:u32 = 1;
+/// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+/// let data:Vec<u8> = (0..60000).map(|_| get_random_byte()).collect();
+/// let mut stats = Bytestat::new();
+/// let mut reference = Bytestat::new();
+/// stats.analyze_slice( &data[..50000] );
+/// reference.analyze_slice( &data[..50000] );
+/// 
+/// let saved = serde_json::to_string(&stats).unwrap();
+/// let mut resumed:Bytestat = serde_json::from_str(&saved).unwrap();
+/// resumed.analyze_slice( &data[50000..] );
+/// reference.analyze_slice( &data[50000..] );
+/// 
+/// assert_eq!(resumed.get_scores_array(), reference.get_scores_array());
+/// assert_eq!(resumed, reference);
+/// # }
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bytestat {
     counter:u128,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     dist:[u128;256],
-    interval:[u128;256*256],
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_count_table"))]
+    interval:CountTable,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_count_table"))]
+    digraph:CountTable,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     last:[u128;256],
-    score_counter:u128,
-    score_non_zero:f64,
-    score_unique:f64,
-    score_amplitude:f64,
-    score_interval_continuity:f64,
-    score_interval_amplitude:f64,
-    score:f64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    first:[u128;256],
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_byte_counts"))]
+    early:ByteCounts,
+    early_count:u128,
+    distinct:u16,
+    dist_squares:u128,
+    interval_top:u16,
+    previous:u8,
+    sum:u128,
+    sum_squares:u128,
+    sum_products:u128,
+    point:u64,
+    point_len:u8,
+    points:u128,
+    points_inside:u128,
+    above:u128,
+    runs:u128,
+    ones:u128,
+    nibbles:[u128;16],
+    walk_max:i128,
+    walk_min:i128,
+    run_length:u128,
+    leading_run:u128,
+    longest_run:u128,
+    #[cfg(feature = "alloc")]
+    lags:lag::LagTracker,
+    #[cfg(feature = "alloc")]
+    maurer:maurer::MaurerTracker,
+    #[cfg(feature = "alloc")]
+    sample:Vec<u8>,
+    #[cfg(feature = "alloc")]
+    sample_size:usize,
+    #[cfg(feature = "alloc")]
+    gaps:Vec<(u8, BTreeMap<u16, u128>)>,
+    #[cfg(feature = "alloc")]
+    history:Vec<(u128, Scores)>,
+    #[cfg(feature = "alloc")]
+    history_interval:u128,
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    progress:ProgressHook,
+    polled:Scores,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_probabilities"))]
+    expected:Option<Probabilities>,
+    max_interval:u16,
+    interval_amplitude_divisor:u16,
+    significance_divisor:u128,
+    weights:Weights,
+    enabled:[bool;5],
 }
 
 impl Bytestat {
+  /// The lowest passing value of each of the five scores, in the order of the fields of `Scores`, see `passes`.
+  pub const PASS_THRESHOLDS:[f64;5] = [1.0, 0.99, 0.99, 0.99, 1.0];
+
   /// Create new Bytestat object.
   ///
+  /// With the `alloc` feature, the interval and digraph tables, 256 KiB each, are allocated on the heap,
+  /// the object itself holds about 13 KiB and can be kept on the stack, even by deeply nested calls.
+  /// To keep it on the heap without going through the stack at all, see `new_boxed`.
+  /// Without it, both tables are held by the object: keep it in a static or make sure the stack is large enough.
+  ///
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
+  /// use libbytestat::Bytestat;
   /// let stats = Bytestat::new();
   /// ```
+  ///
+  /// ```
+  /// # #[cfg(feature = "alloc")] {
+  /// use libbytestat::Bytestat;
+  /// fn nested(depth:u32, data:&[u8]) -> u128 {
+  ///   let mut stats = Bytestat::new();
+  ///   stats.analyze_slice( data );
+  ///   if depth == 0 { stats.get_counter() } else { stats.get_counter() + nested(depth - 1, data) }
+  /// }
+  ///
+  /// assert!(core::mem::size_of::<Bytestat>() < 16 * 1024);
+  /// assert_eq!(nested(100, b"hello"), 505);
+  /// # }
+  /// ```
   pub fn new() -> Bytestat {
     Bytestat {
       counter:0,
       dist:[0;256],
-      interval:[0;256*256],
+      interval:new_count_table(),
+      digraph:new_count_table(),
       last:[0;256],
-      score_counter:0,
-      score_non_zero:0.0,
-      score_unique:0.0,
-      score_amplitude:0.0,
-      score_interval_continuity:0.0,
-      score_interval_amplitude:0.0,
-      score:0.0,
+      first:[0;256],
+      early:new_byte_counts(),
+      early_count:0,
+      distinct:0,
+      dist_squares:0,
+      interval_top:0,
+      previous:0,
+      sum:0,
+      sum_squares:0,
+      sum_products:0,
+      point:0,
+      point_len:0,
+      points:0,
+      points_inside:0,
+      above:0,
+      runs:0,
+      ones:0,
+      nibbles:[0;16],
+      walk_max:0,
+      walk_min:0,
+      run_length:0,
+      leading_run:0,
+      longest_run:0,
+      #[cfg(feature = "alloc")]
+      lags:lag::LagTracker::default(),
+      #[cfg(feature = "alloc")]
+      maurer:maurer::MaurerTracker::default(),
+      #[cfg(feature = "alloc")]
+      sample:Vec::new(),
+      #[cfg(feature = "alloc")]
+      sample_size:0,
+      #[cfg(feature = "alloc")]
+      gaps:Vec::new(),
+      #[cfg(feature = "alloc")]
+      history:Vec::new(),
+      #[cfg(feature = "alloc")]
+      history_interval:0,
+      #[cfg(feature = "alloc")]
+      progress:ProgressHook::default(),
+      polled:Scores {
+        non_zero:0.0,
+        unique:0.0,
+        amplitude:0.0,
+        interval_continuity:0.0,
+        interval_amplitude:0.0,
+        total:0.0,
+      },
+      expected:None,
+      max_interval:u16::MAX,
+      interval_amplitude_divisor:512,
+      significance_divisor:4096,
+      weights:Weights::default(),
+      enabled:[true;5],
       }
   }
 
-  /// Analyze one byte, bytes must be analysed in sequence.
-  /// If bytes are not analyzed in sequence, the final score will not be valid.
-  /// Repeat as needed.
-  ///
-  /// # Arguments
+  /// Create new Bytestat object directly on the heap, with the `alloc` feature.
+  /// `Box::new(Bytestat::new())` builds the object on the stack before moving it to the box,
+  /// about 13 KiB on top of the caller's frame. This constructor zeroes a heap allocation
+  /// and only writes the fields that do not start at zero, the object is never on the stack:
+  /// use it where the stack is small, like in threads, interrupt handlers or deeply nested calls.
   ///
-  /// * `value` - A byte to be analyzed, u8
-  /// 
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
-  /// 
-  /// for x in 0..limit {
-  ///   let my_byte = get_random_byte();
-  ///   stats.analyze( my_byte );
-  /// }
+  /// use libbytestat::Bytestat;
+  /// let mut boxed = Bytestat::new_boxed();
+  /// assert_eq!(*boxed, Bytestat::new());
+  /// assert_eq!(boxed.to_bytes(), Bytestat::new().to_bytes());
+  /// boxed.analyze_slice( b"hello" );
+  /// assert_eq!(*boxed, Bytestat::from_slice( b"hello" ));
+  ///
+  /// let thread = std::thread::Builder::new().stack_size(8 * 1024).spawn(|| {
+  ///   for x in 0..5000u32 {
+  ///     let mut stats = Bytestat::new_boxed();
+  ///     stats.analyze( x as u8 );
+  ///     assert_eq!(stats.get_counter(), 1);
+  ///   }
+  /// }).unwrap();
+  /// thread.join().unwrap();
   /// ```
-  pub fn analyze(&mut self, value:u8) {
-      self.counter += 1;
-      self.dist[value as usize] += 1;
-      self.interval[ ((self.counter - self.last[value as usize]) as u16) as usize ] += 1;
-      self.last[value as usize] = self.counter;
-    }
-
-  fn update_scores(&mut self) {
-    if self.score_counter == self.counter {
-      return
-    }
-
-    //1 of 5
-    let mut dist_not_zero = 0;
-    for x in self.dist {
-      if x > 0 {
-        dist_not_zero += 1;
-      }
-    }
-    self.score_non_zero = dist_not_zero as f64 / 256 as f64;
-
-    //2 of 5
-    let mut dist_unique = 0;
-    let mut dist_unique_map:std::collections::HashMap<u128, i32> = std::collections::HashMap::new();
-    for x in 0..256 {
-      match dist_unique_map.get(&self.dist[x]) {
-        Some(value) => dist_unique_map.insert(self.dist[x], 1+value),
-        None => dist_unique_map.insert(self.dist[x], 1)
-      };
-    }
-    dist_unique_map.values().for_each(|x| {
-      if *x == 1 {
-        dist_unique += 1;
-      }
-    });
-    self.score_unique = dist_unique as f64 / 256 as f64;
-
-    //3 of 5
-    let mut dist_amp_min:u128 = std::u128::MAX;
-    let mut dist_amp_max:u128 = std::u128::MIN;
-    for x in self.dist {
-      if x < dist_amp_min {
-        dist_amp_min = x;
-      }
-      if x > dist_amp_max {
-        dist_amp_max = x;
-      }
-    }
-    let dist_amp_variation = dist_amp_max - dist_amp_min;
-    self.score_amplitude = (dist_amp_max - dist_amp_variation) as f64 / dist_amp_max as f64;
-
-    //4 of 5
-    let mut interval_min = std::u16::MAX;
-    let mut interval_max = std::u16::MIN;
-
-    for x in 1..self.interval.len() {
-      if self.interval[x] > self.counter / 4096 {
-        if (x as u16) < interval_min {
-          interval_min = x as u16;
-        }
-        if (x as u16) > interval_max {
-          interval_max = x as u16;
-        }
-      }
-    }
-
-    let mut populated = 1;
-    for x in 1..interval_max {
-      if self.interval[x as usize] > self.counter / 4096 {
-        populated += 1;
+  #[cfg(feature = "alloc")]
+  pub fn new_boxed() -> Box<Bytestat> {
+    use core::ptr::addr_of_mut;
+    let layout = alloc::alloc::Layout::new::<Bytestat>();
+    // SAFETY: the layout is the one of Bytestat, which is not zero sized. Every field that is not valid
+    // as zeroed memory, or that does not start at zero, is written before the box is created:
+    // the remaining fields are integers, floats and bools, for which zero is the initial value of `new`.
+    unsafe {
+      let stats = alloc::alloc::alloc_zeroed(layout) as *mut Bytestat;
+      if stats.is_null() {
+        alloc::alloc::handle_alloc_error(layout);
       }
+      addr_of_mut!((*stats).interval).write(new_count_table());
+      addr_of_mut!((*stats).digraph).write(new_count_table());
+      addr_of_mut!((*stats).early).write(new_byte_counts());
+      addr_of_mut!((*stats).lags).write(lag::LagTracker::default());
+      addr_of_mut!((*stats).maurer).write(maurer::MaurerTracker::default());
+      addr_of_mut!((*stats).sample).write(Vec::new());
+      addr_of_mut!((*stats).gaps).write(Vec::new());
+      addr_of_mut!((*stats).history).write(Vec::new());
+      addr_of_mut!((*stats).progress).write(ProgressHook::default());
+      addr_of_mut!((*stats).expected).write(None);
+      addr_of_mut!((*stats).max_interval).write(u16::MAX);
+      addr_of_mut!((*stats).interval_amplitude_divisor).write(512);
+      addr_of_mut!((*stats).significance_divisor).write(4096);
+      addr_of_mut!((*stats).weights).write(Weights::default());
+      addr_of_mut!((*stats).enabled).write([true;5]);
+      Box::from_raw(stats)
     }
-    self.score_interval_continuity = (if populated < 512 { populated } else { 512 }) as f64 / 512 as f64;
-
-    //5 of 5
-    if interval_max > 512 {
-      interval_max = 512;
-    }
-    self.score_interval_amplitude = interval_max as f64 / 512 as f64;
-
-    //FINAL SCORE
-    self.score = self.score_non_zero * 20f64;
-    self.score += self.score_unique * 20f64;
-    self.score += self.score_amplitude * 20f64;
-    self.score += self.score_interval_continuity * 20f64;
-    self.score += self.score_interval_amplitude * 20f64;
-
+  }
 
-    self.score_counter = self.counter;
+  /// Create a builder, to configure a new Bytestat object, see `BytestatBuilder`.
+  pub fn builder() -> BytestatBuilder {
+    BytestatBuilder::new()
   }
 
-  /// Generate the score based on distribution of unique bytes being present in the set.
-  /// 
-  /// (unique byte present in set) / (maximum number of possible unique bytes, 256)
+  /// Create new Bytestat object, with custom weights for the final score.
+  /// The weights are checked as by `set_weights`.
+  ///
+  /// # Arguments
   ///
+  /// * `weights` - The weight of each score in the final score, Weights
+  /// 
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
-  /// 
-  /// for x in 0..limit {
-  ///   let my_byte = get_random_byte();
-  ///   stats.analyze( my_byte );
-  /// }
+  /// use libbytestat::{Bytestat, Weights};
+  /// let weights = Weights {
+  ///   non_zero:10.0,
+  ///   unique:10.0,
+  ///   amplitude:20.0,
+  ///   interval_continuity:30.0,
+  ///   interval_amplitude:30.0,
+  /// };
+  /// let mut weighted = Bytestat::with_weights( weights ).unwrap();
+  /// let mut stats = Bytestat::new();
+  /// weighted.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// stats.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
   /// 
-  /// stats.get_score_non_zero()
+  /// let scores = weighted.get_scores();
+  /// assert_eq!(scores.non_zero, stats.get_score_non_zero());
+  /// assert_eq!(scores.interval_amplitude, stats.get_score_interval_amplitude());
+  /// let expected = scores.non_zero * 10.0 + scores.unique * 10.0 + scores.amplitude * 20.0
+  ///   + scores.interval_continuity * 30.0 + scores.interval_amplitude * 30.0;
+  /// assert!((scores.total - expected).abs() < 1e-9);
+  /// assert_ne!(scores.total, stats.get_score());
   /// ```
-  pub fn get_score_non_zero(&mut self) -> f64 {
-    self.update_scores();
-    self.score_non_zero
+  pub fn with_weights(weights:Weights) -> Result<Bytestat, BytestatError> {
+    let mut stats = Bytestat::new();
+    stats.set_weights(weights)?;
+    Ok(stats)
   }
 
-  /// Generate the score based on the uniqueness of the bytes distribution in the set.
-  /// The score is between 0.0 and 1.0. Any score lower than 0.99 should be considered problematic.
-  /// 
-  /// (unique byte count in set) / (maximum number of possible unique bytes, 256)
+  /// Create new Bytestat object and analyze a slice of bytes, ready to be scored.
+  ///
+  /// # Arguments
   ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  /// 
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
-  /// 
-  /// for x in 0..limit {
-  ///   let my_byte = get_random_byte();
-  ///   stats.analyze( my_byte );
-  /// }
-  /// 
-  /// stats.get_score_unique()
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( b"hello" );
+  /// assert_eq!(stats.get_counter(), 5);
+  /// assert!(stats.get_score() < 50.0);
   /// ```
-  pub fn get_score_unique(&mut self) -> f64 {
-    self.update_scores();
-    self.score_unique
+  pub fn from_slice(data:&[u8]) -> Bytestat {
+    let mut stats = Bytestat::new();
+    stats.analyze_slice(data);
+    stats
   }
 
-  /// Generate the score based on the amplitude of the bytes distribution in the set.
-  /// The score is between 0.0 and 1.0. 
-  /// Any score lower than 0.99 should be considered problematic.
-  /// 
-  /// ((bytes count max) - (bytes count min)) / (bytes count max)
+  /// Create new Bytestat object and analyze the bytes decoded from a base64 string, with the `base64` feature.
+  /// The standard alphabet is used, the `=` padding is optional and whitespace is ignored.
+  /// Returns `BytestatError::InvalidBase64` when the string can not be decoded.
+  ///
+  /// # Arguments
   ///
+  /// * `base64` - The base64 encoding of the bytes to be analyzed, &str
+  /// 
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
-  /// 
-  /// for x in 0..limit {
-  ///   let my_byte = get_random_byte();
-  ///   stats.analyze( my_byte );
-  /// }
+  /// # #[cfg(feature = "base64")] {
+  /// use libbytestat::{Base64Error, Bytestat, BytestatError};
+  /// let stats = Bytestat::from_base64( "aGVsbG8g\nd29ybGQ=" ).unwrap();
   /// 
-  /// stats.get_score_amplitude()
+  /// assert_eq!(stats.get_distribution(), Bytestat::from_slice( b"hello world" ).get_distribution());
+  /// assert_eq!(Bytestat::from_base64( "aGk" ).unwrap().get_counter(), 2);
+  /// assert!(matches!(Bytestat::from_base64( "aGk*" ), Err(BytestatError::InvalidBase64(Base64Error::InvalidCharacter('*')))));
+  /// assert!(matches!(Bytestat::from_base64( "aGVsb" ), Err(BytestatError::InvalidBase64(Base64Error::InvalidLength))));
+  /// # }
   /// ```
-  pub fn get_score_amplitude(&mut self) -> f64 {
-    self.update_scores();
-    self.score_amplitude
+  #[cfg(feature = "base64")]
+  pub fn from_base64(base64:&str) -> Result<Bytestat, BytestatError> {
+    let mut stats = Bytestat::new();
+    decode_base64(base64, |value| stats.analyze(value))?;
+    Ok(stats)
   }
 
-  /// Generate the sub score based on the amplitude of the continuity of significant interval measurements.
-  /// The score is between 0 and 1. 
-  /// Any score lower than 0.99 should be considered problematic.
-  /// 
-  /// ( 1 ... interval_largest [] ) / (interval_largest)
+  /// Create new Bytestat object and analyze every byte produced by a reader, see `analyze_reader`.
+  /// On a read error, the error is returned as `BytestatError::Io` and the bytes read are lost.
   ///
+  /// # Arguments
+  ///
+  /// * `reader` - The source of the bytes to be analyzed, impl std::io::Read
+  /// 
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
-  /// 
-  /// for x in 0..limit {
-  ///   let my_byte = get_random_byte();
-  ///   stats.analyze( my_byte );
-  /// }
-  /// 
-  /// stats.get_score_amplitude()
+  /// use libbytestat::Bytestat;
+  /// let data:Vec<u8> = (0..20000u32).map(|x| (x * 7 % 251) as u8).collect();
+  /// let stats = Bytestat::from_reader( data.as_slice() ).unwrap();
+  /// assert_eq!(stats, Bytestat::from_slice( &data ));
   /// ```
-  pub fn get_score_interval_continuity(&mut self) -> f64 {
-    self.update_scores();
-    self.score_interval_continuity
+  #[cfg(feature = "std")]
+  pub fn from_reader<R: std::io::Read>(reader:R) -> Result<Bytestat, BytestatError> {
+    let mut stats = Bytestat::new();
+    stats.analyze_reader(reader)?;
+    Ok(stats)
   }
 
-  /// Generate the score based on the amplitude of significant interval measurements relative to twice the range of byte.
-  /// The score is between 0.0 and 1.0. Any score lower than 1.0 should be considered problematic.
-  /// 
-  /// ( interval_largest ) / 512
+  /// Save the whole analysis in a compact binary snapshot, with the `alloc` feature, to be resumed by `from_bytes`.
+  /// Unlike serde, no dependency is needed. The progress hook is not saved.
+  ///
+  /// Every value is little-endian, the lengths and sizes are u64. The snapshot is, in order:
+  ///
+  /// * the layout version, u8, currently 1
+  /// * the byte counter, u128
+  /// * the distribution, 256 u128
+  /// * the interval table, 65536 u32
+  /// * the last position of each byte value, 256 u128
+  /// * the byte pair table, 65536 u32
+  /// * the first position of each byte value, 256 u128
+  /// * the early distribution, 256 u128, and its byte count, u128
+  /// * the largest interval recorded, u16, and the previous byte, u8
+  /// * the sum of the bytes, of their squares and of the products of consecutive bytes, 3 u128
+  /// * the pending Monte Carlo coordinates, u64, and their length in bytes, u8
+  /// * the Monte Carlo points and the points inside the circle, 2 u128
+  /// * the bytes above the median, the runs and the bits set, 3 u128
+  /// * the nibble distribution, 16 u128
+  /// * the highest and lowest positions of the random walk, 2 i128
+  /// * the current, leading and longest runs, 3 u128
+  /// * the lag history: the maximum lag, the length and bytes of the head, the length and bytes of the tail,
+  ///   then one u128 sum by lag
+  /// * Maurer's test: the block size, u8, the pending bits, u32, and their number, u8, the blocks, u64,
+  ///   the sum, f64, then the last position of each block, u64
+  /// * the sample size, the length and bytes of the sample
+  /// * the number of tracked values, then for each: the value, u8, the number of gaps,
+  ///   and each gap, u16, with its count, u128
+  /// * the history interval, u128, the number of entries, then for each: the byte count, u128, and 6 f64 scores
+  /// * the last polled scores, 6 f64
+  /// * 1 and the 256 f64 expected probabilities, or 0, u8
+  /// * the maximum interval tracked and the interval amplitude divisor, 2 u16, the significance divisor, u128
+  /// * the 5 f64 weights and the 5 enabled scores, 0 or 1, u8
+  ///
+  /// The scores are in the order of the fields of `Scores`, the weights in the order of the fields of `Weights`.
+  /// The snapshot of a new analysis takes about 530 KiB, mostly the interval and byte pair tables.
+  #[cfg(feature = "alloc")]
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut snapshot = snapshot::SnapshotWriter::new(541_000 + self.sample.len());
+    snapshot.u8(SNAPSHOT_VERSION);
+    snapshot.u128(self.counter);
+    for count in self.dist {
+      snapshot.u128(count);
+    }
+    for count in self.interval.iter() {
+      snapshot.u32(*count);
+    }
+    for position in self.last {
+      snapshot.u128(position);
+    }
+    for count in self.digraph.iter() {
+      snapshot.u32(*count);
+    }
+    for position in self.first {
+      snapshot.u128(position);
+    }
+    for count in self.early.iter() {
+      snapshot.u128(*count);
+    }
+    snapshot.u128(self.early_count);
+    snapshot.u16(self.interval_top);
+    snapshot.u8(self.previous);
+    snapshot.u128(self.sum);
+    snapshot.u128(self.sum_squares);
+    snapshot.u128(self.sum_products);
+    snapshot.u64(self.point);
+    snapshot.u8(self.point_len);
+    snapshot.u128(self.points);
+    snapshot.u128(self.points_inside);
+    snapshot.u128(self.above);
+    snapshot.u128(self.runs);
+    snapshot.u128(self.ones);
+    for count in self.nibbles {
+      snapshot.u128(count);
+    }
+    snapshot.i128(self.walk_max);
+    snapshot.i128(self.walk_min);
+    snapshot.u128(self.run_length);
+    snapshot.u128(self.leading_run);
+    snapshot.u128(self.longest_run);
+    self.lags.write_snapshot(&mut snapshot);
+    self.maurer.write_snapshot(&mut snapshot);
+    snapshot.len(self.sample_size);
+    snapshot.len(self.sample.len());
+    snapshot.bytes(&self.sample);
+    snapshot.len(self.gaps.len());
+    for (value, gaps) in &self.gaps {
+      snapshot.u8(*value);
+      snapshot.len(gaps.len());
+      for (gap, count) in gaps {
+        snapshot.u16(*gap);
+        snapshot.u128(*count);
+      }
+    }
+    snapshot.u128(self.history_interval);
+    snapshot.len(self.history.len());
+    for (counter, scores) in &self.history {
+      snapshot.u128(*counter);
+      snapshot.scores(scores);
+    }
+    snapshot.scores(&self.polled);
+    match &self.expected {
+      Some(probabilities) => {
+        snapshot.u8(1);
+        for probability in probabilities.iter() {
+          snapshot.f64(*probability);
+        }
+      }
+      None => snapshot.u8(0)
+    }
+    snapshot.u16(self.max_interval);
+    snapshot.u16(self.interval_amplitude_divisor);
+    snapshot.u128(self.significance_divisor);
+    snapshot.weights(&self.weights);
+    for enabled in self.enabled {
+      snapshot.u8(enabled as u8);
+    }
+    snapshot.into_inner()
+  }
+
+  /// Load an analysis saved by `to_bytes`, with the `alloc` feature, to resume it.
+  /// The resumed analysis gives the same results as the saved one, and keeps doing so as more bytes are analyzed.
+  /// The snapshot is checked: an unknown version, a missing or extra byte, an impossible value
+  /// or counts that do not add up, see `validate`, return `BytestatError::InvalidSnapshot`.
   ///
+  /// # Arguments
+  ///
+  /// * `data` - A snapshot written by `to_bytes`, &[u8]
+  /// 
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
+  /// use libbytestat::{Bytestat, BytestatError, SnapshotError};
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..110000).map(|_| get_random_byte()).collect();
+  /// let mut stats = Bytestat::with_max_lag( 8 );
+  /// let mut reference = Bytestat::with_max_lag( 8 );
+  /// stats.analyze_slice( &data[..100000] );
+  /// reference.analyze_slice( &data[..100000] );
   /// 
-  /// for x in 0..limit {
-  ///   let my_byte = get_random_byte();
-  ///   stats.analyze( my_byte );
-  /// }
-  /// 
-  /// stats.get_score_interval_amplitude()
+  /// let saved = stats.to_bytes();
+  /// let mut resumed = Bytestat::from_bytes( &saved ).unwrap();
+  /// assert_eq!(resumed, stats);
+  /// resumed.analyze_slice( &data[100000..] );
+  /// reference.analyze_slice( &data[100000..] );
+  /// assert_eq!(resumed.get_scores_array(), reference.get_scores_array());
+  /// assert_eq!(resumed, reference);
+  ///
+  /// assert!(matches!(Bytestat::from_bytes( &saved[..saved.len() - 1] ), Err(BytestatError::InvalidSnapshot(SnapshotError::Truncated))));
+  /// let mut altered = saved.clone();
+  /// altered[0] = 2;
+  /// assert!(matches!(Bytestat::from_bytes( &altered ), Err(BytestatError::InvalidSnapshot(SnapshotError::UnsupportedVersion(2)))));
+  /// altered[0] = 1;
+  /// altered[1] ^= 1;
+  /// assert!(matches!(Bytestat::from_bytes( &altered ), Err(BytestatError::InvalidSnapshot(SnapshotError::Corrupted))));
   /// ```
-  pub fn get_score_interval_amplitude(&mut self) -> f64 {
-    self.update_scores();
-    self.score_interval_amplitude
+  #[cfg(feature = "alloc")]
+  pub fn from_bytes(data:&[u8]) -> Result<Bytestat, BytestatError> {
+    let mut snapshot = snapshot::SnapshotReader::new(data);
+    let version = snapshot.u8()?;
+    if version != SNAPSHOT_VERSION {
+      return Err(SnapshotError::UnsupportedVersion(version).into())
+    }
+    let mut stats = Bytestat::new();
+    stats.counter = snapshot.u128()?;
+    for count in stats.dist.iter_mut() {
+      *count = snapshot.u128()?;
+    }
+    for count in stats.interval.iter_mut() {
+      *count = snapshot.u32()?;
+    }
+    for position in stats.last.iter_mut() {
+      *position = snapshot.u128()?;
+    }
+    for count in stats.digraph.iter_mut() {
+      *count = snapshot.u32()?;
+    }
+    for position in stats.first.iter_mut() {
+      *position = snapshot.u128()?;
+    }
+    for count in stats.early.iter_mut() {
+      *count = snapshot.u128()?;
+    }
+    stats.early_count = snapshot.u128()?;
+    stats.interval_top = snapshot.u16()?;
+    stats.previous = snapshot.u8()?;
+    stats.sum = snapshot.u128()?;
+    stats.sum_squares = snapshot.u128()?;
+    stats.sum_products = snapshot.u128()?;
+    stats.point = snapshot.u64()?;
+    stats.point_len = snapshot.u8()?;
+    stats.points = snapshot.u128()?;
+    stats.points_inside = snapshot.u128()?;
+    stats.above = snapshot.u128()?;
+    stats.runs = snapshot.u128()?;
+    stats.ones = snapshot.u128()?;
+    for count in stats.nibbles.iter_mut() {
+      *count = snapshot.u128()?;
+    }
+    stats.walk_max = snapshot.i128()?;
+    stats.walk_min = snapshot.i128()?;
+    stats.run_length = snapshot.u128()?;
+    stats.leading_run = snapshot.u128()?;
+    stats.longest_run = snapshot.u128()?;
+    stats.lags = lag::LagTracker::read_snapshot(&mut snapshot)?;
+    stats.maurer = maurer::MaurerTracker::read_snapshot(&mut snapshot)?;
+    stats.sample_size = snapshot.size()?;
+    let len = snapshot.len(1)?;
+    stats.sample = snapshot.bytes(len)?.to_vec();
+    for _ in 0..snapshot.len(9)? {
+      let value = snapshot.u8()?;
+      let mut gaps = BTreeMap::new();
+      for _ in 0..snapshot.len(18)? {
+        let gap = snapshot.u16()?;
+        if gaps.insert(gap, snapshot.u128()?).is_some() {
+          return Err(SnapshotError::Corrupted.into())
+        }
+      }
+      if stats.gaps.iter().any(|(tracked, _)| *tracked == value) {
+        return Err(SnapshotError::Corrupted.into())
+      }
+      stats.gaps.push((value, gaps));
+    }
+    stats.history_interval = snapshot.u128()?;
+    for _ in 0..snapshot.len(64)? {
+      let counter = snapshot.u128()?;
+      stats.history.push((counter, snapshot.scores()?));
+    }
+    stats.polled = snapshot.scores()?;
+    if snapshot.bool()? {
+      let mut probabilities = [0.0;256];
+      for probability in probabilities.iter_mut() {
+        *probability = snapshot.f64()?;
+      }
+      stats.set_expected_distribution(probabilities).map_err(|_| SnapshotError::Corrupted)?;
+    }
+    stats.max_interval = snapshot.u16()?;
+    stats.interval_amplitude_divisor = snapshot.u16()?;
+    stats.significance_divisor = snapshot.u128()?;
+    let weights = snapshot.weights()?;
+    stats.set_weights(weights).map_err(|_| SnapshotError::Corrupted)?;
+    for enabled in stats.enabled.iter_mut() {
+      *enabled = snapshot.bool()?;
+    }
+    if !snapshot.is_empty() {
+      return Err(SnapshotError::TrailingBytes.into())
+    }
+    // The distinct count and the sum of the squared counts are not saved, they follow from the distribution.
+    let mut total:u128 = 0;
+    for count in stats.dist {
+      total = total.checked_add(count).ok_or(SnapshotError::Corrupted)?;
+      let square = count.checked_mul(count).ok_or(SnapshotError::Corrupted)?;
+      stats.dist_squares = stats.dist_squares.checked_add(square).ok_or(SnapshotError::Corrupted)?;
+      if count > 0 {
+        stats.distinct += 1;
+      }
+    }
+    if total != stats.counter || stats.ones > stats.counter.saturating_mul(8) || stats.points_inside > stats.points
+      || !stats.validate() || stats.point_len >= 6 || stats.point >> (8 * stats.point_len) != 0 || stats.early_count > stats.counter
+      || stats.max_interval == 0 || stats.interval_amplitude_divisor == 0 || stats.interval_top > stats.max_interval || stats.significance_divisor == 0
+      || stats.sample.len() > stats.sample_size || stats.walk_min > 0 || stats.walk_max < 0 {
+      return Err(SnapshotError::Corrupted.into())
+    }
+    Ok(stats)
   }
 
-  /// Generate the final score based on the 5 individual tests. 
-  /// Score between 0 and 100. 99 or lower is very problematic.
+  /// Create new Bytestat object, keeping enough history for `get_autocorrelation` up to `max_lag`, with the `alloc` feature.
+  /// The first and last `max_lag` bytes are kept, along with one running sum per lag,
+  /// about 18 bytes of memory per lag, and each analyzed byte costs `max_lag` multiplications.
+  /// `new()` keeps no history, `get_autocorrelation` then always returns 0.
+  ///
+  /// # Arguments
+  ///
+  /// * `max_lag` - The largest lag available to `get_autocorrelation`, usize
   ///
   /// # Examples
   ///
   /// ```
-  /// use bytestat::Bytestat;
-  /// let stats = Bytestat::new();
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::with_max_lag( 16 );
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn with_max_lag(max_lag:usize) -> Bytestat {
+    let mut stats = Bytestat::new();
+    stats.lags = lag::LagTracker::new(max_lag);
+    stats
+  }
+
+  /// Create new Bytestat object, running Maurer's universal test on blocks of `block_bits` bits, with the `alloc` feature.
+  /// The block size is between 1 and 16 bits, larger sizes are treated as 16, see `get_maurer_universal`.
+  /// The last position of each of the 2^block_bits possible blocks is kept, 8 bytes of memory per block.
+  /// `new()` does not run the test, a block size of 0 does not either.
+  ///
+  /// # Arguments
+  ///
+  /// * `block_bits` - The number of bits in each block, u8
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::with_maurer_block_bits( 8 );
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn with_maurer_block_bits(block_bits:u8) -> Bytestat {
+    let mut stats = Bytestat::new();
+    stats.maurer = maurer::MaurerTracker::new(block_bits.min(16));
+    stats
+  }
+
+  /// Create new Bytestat object, keeping a copy of the first `sample_size` bytes analyzed, with the `alloc` feature.
+  /// The sample is the only copy of the data, `sample_size` bounds the memory of every metric needing the data itself:
+  /// `get_approx_entropy`, `get_block_frequency_score`, `get_spectral_score` and `get_compression_ratio`
+  /// only look at the first `sample_size` bytes.
+  /// It costs 1 byte of memory per byte of `sample_size`, bytes past the sample are analyzed as usual but not kept.
+  /// The other metrics are computed over the whole set in a fixed memory, `get_autocorrelation` keeps
+  /// its own bytes, bounded by `with_max_lag`.
+  /// `new()` keeps no sample.
+  ///
+  /// # Arguments
+  ///
+  /// * `sample_size` - The number of bytes kept, usize
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::with_sample_size( 1000 );
+  /// stats.analyze_slice( &[7u8;800] );
+  /// stats.analyze_slice( &(0..=255u8).cycle().take(100000).collect::<Vec<u8>>() );
+  ///
+  /// assert_eq!(stats.get_sample().len(), 1000);
+  /// assert_eq!(stats.get_sample()[799..801], [7, 0]);
+  /// assert_eq!(stats.get_counter(), 100800);
+  /// assert_eq!(stats.get_score_non_zero(), 1.0);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn with_sample_size(sample_size:usize) -> Bytestat {
+    let mut stats = Bytestat::new();
+    stats.sample = Vec::with_capacity(sample_size);
+    stats.sample_size = sample_size;
+    stats
+  }
+
+  // A new object with the same maximum lag and sample size, to analyze a part of the same set.
+  #[cfg(feature = "rayon")]
+  fn new_like(&self) -> Bytestat {
+    let mut stats = Bytestat::with_max_lag(self.lags.max_lag());
+    stats.sample_size = self.sample_size;
+    stats.max_interval = self.max_interval;
+    stats.interval_amplitude_divisor = self.interval_amplitude_divisor;
+    stats.expected = self.expected.clone();
+    for (value, _) in &self.gaps {
+      stats.track_gaps(*value);
+    }
+    stats
+  }
+
+  /// Record the gaps between the occurrences of `value`, with the `alloc` feature, see `get_gap_distribution`.
+  /// Only the gaps found after this call are recorded, tracking a value already tracked does nothing.
+  /// Each tracked value keeps one count per distinct gap length, at most 65536 counts.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - The byte value whose gaps are recorded, u8
+  #[cfg(feature = "alloc")]
+  pub fn track_gaps(&mut self, value:u8) {
+    if !self.gaps.iter().any(|(tracked, _)| *tracked == value) {
+      self.gaps.push((value, BTreeMap::new()));
+    }
+  }
+
+  /// Set the weight of each score in the final score.
+  /// Each weight must be finite and positive or zero, and the weights must sum to 100, within 1e-9.
+  /// Otherwise `BytestatError::InvalidWeights` is returned and the weights are not changed.
+  ///
+  /// # Arguments
+  ///
+  /// * `weights` - The weight of each score in the final score, Weights
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::{Bytestat, BytestatError, Weights};
+  /// let mut stats = Bytestat::new();
+  /// let even = Weights {
+  ///   non_zero:25.0,
+  ///   unique:25.0,
+  ///   amplitude:25.0,
+  ///   interval_continuity:25.0,
+  ///   interval_amplitude:0.0,
+  /// };
+  /// assert!(stats.set_weights( even ).is_ok());
+  /// 
+  /// let negative = Weights { unique:-25.0, amplitude:75.0, ..even };
+  /// let too_heavy = Weights { interval_amplitude:25.0, ..even };
+  /// let undefined = Weights { non_zero:f64::NAN, ..even };
+  /// for weights in [negative, too_heavy, undefined] {
+  ///   assert!(matches!(stats.set_weights( weights ), Err(BytestatError::InvalidWeights)));
+  /// }
+  /// ```
+  pub fn set_weights(&mut self, weights:Weights) -> Result<(), BytestatError> {
+    let all = [
+      weights.non_zero,
+      weights.unique,
+      weights.amplitude,
+      weights.interval_continuity,
+      weights.interval_amplitude
+    ];
+    if all.iter().any(|x| !x.is_finite() || *x < 0.0) || (all.iter().sum::<f64>() - 100.0).abs() > 1e-9 {
+      return Err(BytestatError::InvalidWeights)
+    }
+    self.weights = weights;
+    Ok(())
+  }
+
+  /// Set the expected probability of each byte value, compared to the distribution by `get_chi_square`,
+  /// `get_chi_square_pvalue` and `get_ks_statistic`, to check data against a known profile, like text.
+  /// The default is the uniform distribution of random data, 1/256 for each byte value. The five scores are not affected.
+  /// Every probability must be finite and positive or zero, and they must sum to 1, within 1e-6.
+  /// Otherwise, `BytestatError::InvalidDistribution` is returned and the expected distribution is not changed.
+  ///
+  /// # Arguments
+  ///
+  /// * `probabilities` - The expected probability of each byte value, [f64;256]
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::{Bytestat, BytestatError};
+  /// // Three bytes out of four are 'a', the others are 'b'.
+  /// let mut expected = [0.0;256];
+  /// expected[b'a' as usize] = 0.75;
+  /// expected[b'b' as usize] = 0.25;
+  /// let mut stats = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..10000 {
+  ///   stats.analyze( if get_random_byte() < 192 { b'a' } else { b'b' } );
+  /// }
+  ///
+  /// assert!(stats.get_chi_square_pvalue() < 1e-9);
+  /// stats.set_expected_distribution( expected ).unwrap();
+  /// assert!(stats.get_chi_square_pvalue() > 0.01);
+  /// assert!(stats.get_ks_statistic() < 1.63 / 10000f64.sqrt());
+  /// expected[0] = 0.5;
+  /// assert!(matches!(stats.set_expected_distribution( expected ), Err(BytestatError::InvalidDistribution)));
+  /// ```
+  pub fn set_expected_distribution(&mut self, probabilities:[f64;256]) -> Result<(), BytestatError> {
+    if probabilities.iter().any(|x| !x.is_finite() || *x < 0.0) || (probabilities.iter().sum::<f64>() - 1.0).abs() > 1e-6 {
+      return Err(BytestatError::InvalidDistribution)
+    }
+    #[cfg(feature = "alloc")]
+    let probabilities = Box::new(probabilities) as Box<[f64]>;
+    self.expected = Some(probabilities);
+    Ok(())
+  }
+
+  /// Reset the analysis, as if the object was just created with `new()`.
+  /// The existing storage is zeroed in place and reused.
+  /// The configuration, like the significance divisor and the weights, is kept.
+  /// The progress hook is removed, see `set_progress_hook`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut reused = Bytestat::new();
+  /// let mut fresh = Bytestat::new();
+  /// 
+  /// reused.analyze_slice( b"first dataset, thrown away" );
+  /// reused.get_score();
+  /// reused.reset();
+  /// reused.analyze_slice( b"second dataset" );
+  /// fresh.analyze_slice( b"second dataset" );
+  /// 
+  /// assert_eq!(reused.get_scores_array(), fresh.get_scores_array());
+  /// ```
+  pub fn reset(&mut self) {
+    self.counter = 0;
+    self.dist.fill(0);
+    self.interval.fill(0);
+    self.digraph.fill(0);
+    self.last.fill(0);
+    self.first.fill(0);
+    self.early.fill(0);
+    self.early_count = 0;
+    self.distinct = 0;
+    self.dist_squares = 0;
+    self.interval_top = 0;
+    self.previous = 0;
+    self.sum = 0;
+    self.sum_squares = 0;
+    self.sum_products = 0;
+    self.point = 0;
+    self.point_len = 0;
+    self.points = 0;
+    self.points_inside = 0;
+    self.above = 0;
+    self.runs = 0;
+    self.ones = 0;
+    self.nibbles.fill(0);
+    self.walk_max = 0;
+    self.walk_min = 0;
+    self.run_length = 0;
+    self.leading_run = 0;
+    self.longest_run = 0;
+    #[cfg(feature = "alloc")]
+    self.lags.reset();
+    #[cfg(feature = "alloc")]
+    self.maurer.reset();
+    #[cfg(feature = "alloc")]
+    self.sample.clear();
+    #[cfg(feature = "alloc")]
+    for (_, gaps) in self.gaps.iter_mut() {
+      gaps.clear();
+    }
+    #[cfg(feature = "alloc")]
+    self.history.clear();
+    #[cfg(feature = "alloc")]
+    {
+      self.progress = ProgressHook::default();
+    }
+    self.polled = self.compute_scores();
+  }
+
+  /// Capture the scores every `interval` bytes, with the `alloc` feature, see `get_history`.
+  /// The scores are captured when the bytes count becomes a multiple of `interval`, each capture computes all the scores.
+  /// An interval of 0 stops the captures, the history already captured is kept until `reset`.
+  /// The history is not merged by `merge`, and the parallel analysis does not capture any scores.
+  ///
+  /// # Arguments
+  ///
+  /// * `interval` - The number of bytes between two captures, u128
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..20000).map(|_| get_random_byte()).collect();
+  /// let mut stats = Bytestat::new();
+  /// stats.enable_history( 4096 );
+  /// stats.analyze_slice( &data );
+  /// 
+  /// let positions:Vec<u128> = stats.get_history().iter().map(|(position, _)| *position).collect();
+  /// assert_eq!(positions, [4096, 8192, 12288, 16384]);
+  /// let mut replay = Bytestat::new();
+  /// replay.analyze_slice( &data[..8192] );
+  /// assert_eq!(stats.get_history()[1].1, replay.snapshot());
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn enable_history(&mut self, interval:u128) {
+    self.history_interval = interval;
+  }
+
+  /// Call `hook` every `every` bytes, with the `alloc` feature, to follow the progress of a long analysis.
+  /// The hook is called when the bytes count becomes a multiple of `every`, with the bytes count and the analysis so far.
+  /// It replaces the previous hook, an interval of 0 removes it. `reset` removes it too.
+  /// The hook is not cloned with the object, not serialized, and not called for the bytes added by `merge`
+  /// or analyzed by the parallel analysis.
+  ///
+  /// # Arguments
+  ///
+  /// * `every` - The number of bytes between two calls, u128
+  /// * `hook` - The function called with the bytes count and the analysis, ProgressFn
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[cfg(feature = "std")] {
+  /// use libbytestat::Bytestat;
+  /// use std::sync::{Arc, Mutex};
+  /// let calls = Arc::new(Mutex::new(Vec::new()));
+  /// let seen = Arc::clone(&calls);
+  /// let mut stats = Bytestat::new();
+  /// stats.set_progress_hook( 4096, Box::new(move |count, stats| {
+  ///   seen.lock().unwrap().push((count, stats.get_counter()));
+  /// }) );
+  /// stats.analyze_reader( &[0u8;20000][..] ).unwrap();
+  /// 
+  /// assert_eq!(*calls.lock().unwrap(), [(4096, 4096), (8192, 8192), (12288, 12288), (16384, 16384)]);
+  /// stats.reset();
+  /// stats.analyze_slice( &[0u8;20000] );
+  /// assert_eq!(calls.lock().unwrap().len(), 4);
+  /// # }
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn set_progress_hook(&mut self, every:u128, hook:ProgressFn) {
+    self.progress = ProgressHook(if every == 0 { None } else { Some((every, hook)) });
+  }
+
+  /// Set the largest interval recorded on its own, larger intervals are all recorded as `max`.
+  /// By default, every interval up to 65535 is recorded on its own. A `max` of 0 is treated as 1.
+  /// Intervals already recorded above `max` are moved to `max`, the interval table keeps its size.
+  ///
+  /// No interval above `max` can be significant, which bounds both interval scores:
+  /// the continuity score counts the significant intervals up to 512, it is at most min(`max`, 512) / 512,
+  /// the amplitude score is at most min(`max`, divisor) / divisor, see `set_interval_amplitude_divisor`.
+  /// With the default divisor of 512, a `max` of 512 or more leaves both scores unchanged on random data.
+  ///
+  /// # Arguments
+  ///
+  /// * `max` - The largest interval recorded on its own, u16
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..1 << 20).map(|_| get_random_byte()).collect();
+  /// let mut stats = Bytestat::new();
+  /// let mut capped = Bytestat::new();
+  /// capped.set_max_interval_tracked( 100 );
+  /// stats.analyze_slice( &data );
+  /// capped.analyze_slice( &data );
+  /// 
+  /// assert_eq!(stats.get_score_interval_continuity(), 1.0);
+  /// assert_eq!(capped.get_score_interval_continuity(), 100.0 / 512.0);
+  /// assert_eq!(capped.get_score_interval_amplitude(), 100.0 / 512.0);
+  /// capped.set_interval_amplitude_divisor( 200 );
+  /// assert_eq!(capped.get_score_interval_amplitude(), 100.0 / 200.0);
+  /// capped.set_interval_amplitude_divisor( 512 );
+  /// assert_eq!(capped.get_interval_histogram()[100..].iter().map(|x| *x as u128).sum::<u128>(),
+  ///   stats.get_interval_histogram()[100..].iter().map(|x| *x as u128).sum::<u128>());
+  /// stats.set_max_interval_tracked( 100 );
+  /// assert_eq!(stats, capped);
+  /// ```
+  pub fn set_max_interval_tracked(&mut self, max:u16) {
+    self.max_interval = max.max(1);
+    let top = self.max_interval as usize;
+    for x in top + 1..self.interval.len() {
+      self.interval[top] = self.interval[top].saturating_add(self.interval[x]);
+      self.interval[x] = 0;
+    }
+    if self.interval_top > self.max_interval {
+      self.interval_top = self.max_interval;
+    }
+  }
+
+  /// Set the divisor used to decide if an interval, or the whole set, is significant.
+  /// The default divisor is 4096, a divisor of 0 is treated as 1.
+  /// 
+  /// An interval is significant when it was recorded more than (bytes count) / divisor times.
+  /// Only significant intervals are counted by the interval continuity and interval amplitude scores,
+  /// a smaller divisor raises the bar and needs more data to reach the same score.
+  /// The set is significant when it contains at least 256 * divisor * 100 bytes, see `is_significant`.
+  ///
+  /// # Arguments
+  ///
+  /// * `divisor` - The significance divisor, u128
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..20000).map(|_| get_random_byte()).collect();
+  /// let mut lenient = Bytestat::new();
+  /// let mut strict = Bytestat::new();
+  /// strict.set_significance_divisor( 256 );
+  /// lenient.analyze_slice( &data );
+  /// strict.analyze_slice( &data );
+  /// 
+  /// assert!(strict.get_score_interval_continuity() < lenient.get_score_interval_continuity());
+  /// assert_eq!(strict.get_score_non_zero(), lenient.get_score_non_zero());
+  /// ```
+  pub fn set_significance_divisor(&mut self, divisor:u128) {
+    self.significance_divisor = if divisor == 0 { 1 } else { divisor };
+  }
+
+  /// Set the divisor of the interval amplitude score, the largest significant interval giving the score 1.0.
+  /// The default divisor is 512, twice the 256 possible byte values, a divisor of 0 is treated as 1.
+  ///
+  /// Random bytes repeat after x bytes with a probability of (1/256) * (255/256)^(x-1): with the default
+  /// significance divisor, the intervals up to about 700 are significant, past the default divisor.
+  /// The largest significant interval is then clamped to 512 and the score stays at 1.0,
+  /// a larger divisor keeps the variations of the largest significant interval visible.
+  ///
+  /// # Arguments
+  ///
+  /// * `divisor` - The interval amplitude divisor, u16
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..1 << 20).map(|_| get_random_byte()).collect();
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &data );
+  /// assert_eq!(stats.get_score_interval_amplitude(), 1.0);
+  ///
+  /// stats.set_interval_amplitude_divisor( 1024 );
+  /// let score = stats.get_score_interval_amplitude();
+  /// assert!(score > 0.6 && score < 0.8);
+  /// assert_eq!(score * 1024.0, (score * 1024.0).round());
+  /// ```
+  pub fn set_interval_amplitude_divisor(&mut self, divisor:u16) {
+    self.interval_amplitude_divisor = divisor.max(1);
+  }
+
+  /// Analyze one byte, bytes must be analysed in sequence.
+  /// If bytes are not analyzed in sequence, the final score will not be valid.
+  /// Repeat as needed.
+  /// The interval since the previous occurrence of the same byte is recorded,
+  /// intervals of 65535 bytes or more are all recorded as 65535.
+  /// The first occurrence of each byte value has no previous occurrence, no interval is recorded for it.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - A byte to be analyzed, u8
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
   /// 
   /// for x in 0..limit {
   ///   let my_byte = get_random_byte();
   ///   stats.analyze( my_byte );
   /// }
+  /// ```
+  ///
+  /// A very long interval is not mistaken for a short one.
+  /// Here, bytes 1 to 255 come back after 65836 bytes, making it the largest significant interval.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// let ramp:Vec<u8> = (1..=255).collect();
+  /// stats.analyze_slice( &ramp );
+  /// stats.analyze_slice( &vec![0u8;65581] );
+  /// stats.analyze_slice( &ramp );
+  /// 
+  /// assert_eq!(stats.get_score_interval_amplitude(), 1.0);
+  /// ```
+  ///
+  /// Only the intervals between repeated bytes count.
+  /// Here, the only interval is 1, the first 2 does not record the interval 3 from the start of the set.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[1, 1, 2, 2] );
   /// 
-  /// stats.get_score()
+  /// assert_eq!(stats.get_score_interval_amplitude(), 1.0 / 512.0);
   /// ```
-  pub fn get_score(&mut self) -> f64 {
-    self.update_scores();
-    self.score
+  pub fn analyze(&mut self, value:u8) {
+      if self.counter > 0 {
+        self.sum_products += self.previous as u128 * value as u128;
+        self.record_digraph(self.previous, value);
+      }
+      if self.counter == 0 || (self.previous > 127) != (value > 127) {
+        self.runs += 1;
+      }
+      if value > 127 {
+        self.above += 1;
+      }
+      if self.counter > 0 && self.previous == value {
+        self.run_length += 1;
+      }
+      else {
+        self.run_length = 1;
+      }
+      if self.run_length > self.longest_run {
+        self.longest_run = self.run_length;
+      }
+      self.counter += 1;
+      let walk = self.walk();
+      if walk > self.walk_max {
+        self.walk_max = walk;
+      }
+      if walk < self.walk_min {
+        self.walk_min = walk;
+      }
+      if self.run_length == self.counter {
+        self.leading_run = self.run_length;
+      }
+      if self.last[value as usize] == 0 {
+        self.first[value as usize] = self.counter;
+      }
+      else {
+        self.record_interval(self.counter - self.last[value as usize]);
+        #[cfg(feature = "alloc")]
+        self.record_gap(value, self.counter - self.last[value as usize], 1);
+      }
+      self.sum += value as u128;
+      self.ones += value.count_ones() as u128;
+      self.nibbles[(value >> 4) as usize] += 1;
+      self.nibbles[(value & 0x0F) as usize] += 1;
+      self.sum_squares += value as u128 * value as u128;
+      #[cfg(feature = "alloc")]
+      self.lags.push(value);
+      #[cfg(feature = "alloc")]
+      self.maurer.push(value);
+      #[cfg(feature = "alloc")]
+      if self.sample.len() < self.sample_size {
+        self.sample.push(value);
+      }
+      self.previous = value;
+      self.add_to_point(value);
+      self.dist[value as usize] += 1;
+      self.last[value as usize] = self.counter;
+      self.update_dist_aggregates(self.dist[value as usize]);
+      self.record_checkpoints();
+    }
+
+  // Take the early distribution, record the history and call the progress hook, when the byte counter calls for it.
+  fn record_checkpoints(&mut self) {
+    if self.counter.is_power_of_two() {
+      self.early.copy_from_slice(&self.dist);
+      self.early_count = self.counter;
+    }
+    #[cfg(feature = "alloc")]
+    if self.history_interval > 0 && self.counter.is_multiple_of(self.history_interval) {
+      self.history.push((self.counter, self.compute_scores()));
+    }
+    #[cfg(feature = "alloc")]
+    if let Some((every, mut hook)) = self.progress.0.take() {
+      if self.counter.is_multiple_of(every) {
+        hook(self.counter, self);
+      }
+      self.progress.0 = Some((every, hook));
+    }
   }
 
-  pub fn get_scores_array(&mut self) -> [f64;6] {
-    [
-      self.get_score_non_zero(),
-      self.get_score_unique(),
-      self.get_score_amplitude(),
-      self.get_score_interval_continuity(),
-      self.get_score_interval_amplitude(),
-      self.get_score()
-    ]
+  // Number of bytes to analyze before the next call of `record_checkpoints` that does something.
+  fn bytes_until_checkpoint(&self) -> u128 {
+    let bytes = (self.counter + 1).checked_next_power_of_two().unwrap_or(u128::MAX) - self.counter;
+    #[cfg(feature = "alloc")]
+    let bytes = match self.history_interval {
+      0 => bytes,
+      interval => bytes.min(interval - self.counter % interval)
+    };
+    #[cfg(feature = "alloc")]
+    let bytes = match &self.progress.0 {
+      Some((every, _)) => bytes.min(every - self.counter % every),
+      None => bytes
+    };
+    bytes
   }
 
-  pub fn get_scores_string(&mut self, seperator:&str) -> String {
-    let mut answer = String::from("");
+  /// Analyze the same byte `count` times in a row, as run-length encoded data would give it.
+  /// Equivalent to calling `analyze` `count` times, every metric is the same.
+  /// The byte is analyzed once, the following repetitions only add to the counts, each recording an interval of 1,
+  /// and the history and the progress hook are recorded at the same positions as with `analyze`.
+  /// Without Maurer's universal test the cost does not grow with `count`.
+  /// With it, see `with_maurer_block_bits`, the test still runs once per repetition: the cost is the one of `analyze`,
+  /// and a count in the trillions keeps the call busy for hours.
+  ///
+  /// The unique and amplitude scores multiply the squared counts by 256, the whole analysis must stay below 2^60 bytes,
+  /// `count` included. Beyond, the counts overflow, as they would after as many calls to `analyze`.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - The byte to be analyzed, u8
+  /// * `count` - The number of times it is repeated, u128
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[cfg(feature = "alloc")] {
+  /// use libbytestat::Bytestat;
+  /// let mut repeated = Bytestat::with_max_lag( 4 );
+  /// let mut expanded = Bytestat::with_max_lag( 4 );
+  /// for (value, count) in [(3, 1), (200, 100000), (3, 7), (0, 0), (3, 2)] {
+  ///   repeated.analyze_repeated( value, count );
+  ///   expanded.analyze_slice( &vec![value;count as usize] );
+  /// }
+  ///
+  /// assert_eq!(repeated, expanded);
+  /// assert_eq!(repeated.get_all_metrics(), expanded.get_all_metrics());
+  /// assert_eq!(repeated.get_distribution()[200], 100000);
+  /// // A trillion bytes, at once.
+  /// repeated.analyze_repeated( 200, 1 << 40 );
+  /// assert_eq!(repeated.get_counter(), (1 << 40) + 100010);
+  /// # }
+  /// ```
+  pub fn analyze_repeated(&mut self, value:u8, count:u128) {
+    if count == 0 {
+      return
+    }
+    self.analyze(value);
+    let mut remaining = count - 1;
+    while remaining > 0 {
+      let bytes = remaining.min(self.bytes_until_checkpoint());
+      self.repeat_previous(bytes);
+      self.record_checkpoints();
+      remaining -= bytes;
+    }
+  }
+
+  // Analyze the previous byte `count` more times, without the checkpoints.
+  fn repeat_previous(&mut self, count:u128) {
+    let value = self.previous;
+    let square = value as u128 * value as u128;
+    self.sum_products += square * count;
+    let slot = (value as usize) << 8 | value as usize;
+    self.digraph[slot] = self.digraph[slot].saturating_add(count.min(u32::MAX as u128) as u32);
+    if value > 127 {
+      self.above += count;
+    }
+    self.run_length += count;
+    if self.run_length > self.longest_run {
+      self.longest_run = self.run_length;
+    }
+    self.counter += count;
+    // The walk moves the same way on every repetition, its extremes are at the start or at the end.
+    let walk = self.walk();
+    if walk > self.walk_max {
+      self.walk_max = walk;
+    }
+    if walk < self.walk_min {
+      self.walk_min = walk;
+    }
+    if self.run_length == self.counter {
+      self.leading_run = self.run_length;
+    }
+    self.record_intervals(1, count);
+    #[cfg(feature = "alloc")]
+    self.record_gap(value, 1, count);
+    self.sum += value as u128 * count;
+    self.ones += value.count_ones() as u128 * count;
+    self.nibbles[(value >> 4) as usize] += count;
+    self.nibbles[(value & 0x0F) as usize] += count;
+    self.sum_squares += square * count;
+    #[cfg(feature = "alloc")]
+    self.lags.push_repeated(value, count);
+    #[cfg(feature = "alloc")]
+    self.maurer.push_repeated(value, count);
+    #[cfg(feature = "alloc")]
+    {
+      let missing = (self.sample_size - self.sample.len()) as u128;
+      let len = self.sample.len() + count.min(missing) as usize;
+      self.sample.resize(len, value);
+    }
+    // The coordinates of every complete point are the same repeated byte.
+    let mut points = count;
+    while points > 0 && self.point_len > 0 {
+      self.add_to_point(value);
+      points -= 1;
+    }
+    let x = u64::from_be_bytes([0, 0, 0, 0, 0, value, value, value]);
+    if x * x + x * x <= 0xFF_FFFF * 0xFF_FFFF {
+      self.points_inside += points / 6;
+    }
+    self.points += points / 6;
+    for _ in 0..points % 6 {
+      self.add_to_point(value);
+    }
+    let previous_count = self.dist[value as usize];
+    self.dist[value as usize] += count;
+    self.last[value as usize] = self.counter;
+    self.dist_squares += 2 * previous_count * count + count * count;
+  }
+
+  // Add one byte to the coordinates of the next Monte Carlo point, counting the point once complete.
+  fn add_to_point(&mut self, value:u8) {
+    self.point = (self.point << 8) | value as u64;
+    self.point_len += 1;
+    if self.point_len == 6 {
+      let x = self.point >> 24;
+      let y = self.point & 0xFF_FFFF;
+      if x * x + y * y <= 0xFF_FFFF * 0xFF_FFFF {
+        self.points_inside += 1;
+      }
+      self.points += 1;
+      self.point = 0;
+      self.point_len = 0;
+    }
+  }
 
-    answer.push_str( self.get_score_non_zero().to_string().as_str() );
-    answer.push_str( seperator );
+  fn record_digraph(&mut self, previous:u8, value:u8) {
+    let slot = (previous as usize) << 8 | value as usize;
+    self.digraph[slot] = self.digraph[slot].saturating_add(1);
+  }
 
-    answer.push_str( self.get_score_unique().to_string().as_str() );
-    answer.push_str( seperator );
+  #[cfg(feature = "alloc")]
+  fn record_gap(&mut self, value:u8, gap:u128, count:u128) {
+    if let Some((_, gaps)) = self.gaps.iter_mut().find(|(tracked, _)| *tracked == value) {
+      *gaps.entry(interval_slot(gap) as u16).or_insert(0) += count;
+    }
+  }
 
-    answer.push_str( self.get_score_amplitude().to_string().as_str() );
-    answer.push_str( seperator );
+  fn record_interval(&mut self, gap:u128) {
+    self.record_intervals(gap, 1);
+  }
 
-    answer.push_str( self.get_score_interval_continuity().to_string().as_str() );
-    answer.push_str( seperator );
+  fn record_intervals(&mut self, gap:u128, count:u128) {
+    let slot = interval_slot(gap).min(self.max_interval as usize);
+    self.interval[slot] = self.interval[slot].saturating_add(count.min(u32::MAX as u128) as u32);
+    if slot as u16 > self.interval_top {
+      self.interval_top = slot as u16;
+    }
+  }
 
-    answer.push_str( self.get_score_interval_amplitude().to_string().as_str() );
-    answer.push_str( seperator );
+  // Keep the distinct count and the sum of the squared counts current after one count reached `count`.
+  fn update_dist_aggregates(&mut self, count:u128) {
+    if count == 1 {
+      self.distinct += 1;
+    }
+    self.dist_squares += 2 * count - 1;
+  }
 
-    answer.push_str( self.get_score().to_string().as_str() );
+  // Remove one occurrence of `value` from the distribution,
+  // along with the interval to its next occurrence when `next_gap` is not 0.
+  #[cfg(feature = "alloc")]
+  fn forget(&mut self, value:u8, next_gap:u128) {
+    let count = self.dist[value as usize];
+    self.dist[value as usize] -= 1;
+    if count == 1 {
+      self.distinct -= 1;
+    }
+    self.dist_squares -= 2 * count - 1;
+    if next_gap > 0 {
+      let slot = interval_slot(next_gap).min(self.max_interval as usize);
+      self.interval[slot] = self.interval[slot].saturating_sub(1);
+    }
+  }
 
-    answer
+  /// Analyze a slice of bytes, in order.
+  /// Equivalent to calling `analyze` on each byte of the slice.
+  /// An empty slice leaves the analysis untouched.
+  ///
+  /// A set too large for memory can be fed in chunks to the same object: the positions of the last occurrences,
+  /// the previous byte and every other running state carry over from one call to the next,
+  /// the intervals and byte pairs across a chunk boundary are recorded as if the set was analyzed at once.
+  /// Chunks analyzed by separate objects can be joined with `merge`.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut bulk = Bytestat::new();
+  /// let mut single = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..10240).map(|_| get_random_byte()).collect();
+  /// 
+  /// bulk.analyze_slice( &data );
+  /// bulk.analyze_slice( &[] );
+  /// for x in &data {
+  ///   single.analyze( *x );
+  /// }
+  /// 
+  /// assert_eq!(bulk.get_scores_array(), single.get_scores_array());
+  /// ```
+  ///
+  /// Feeding the set in chunks of any size gives the same analysis as feeding it at once.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..100000).map(|_| get_random_byte()).collect();
+  /// let mut whole = Bytestat::new();
+  /// whole.analyze_slice( &data );
+  /// 
+  /// for size in [1, 5, 4096, 65537] {
+  ///   let mut chunked = Bytestat::new();
+  ///   for chunk in data.chunks(size) {
+  ///     chunked.analyze_slice( chunk );
+  ///   }
+  /// 
+  ///   assert_eq!(chunked, whole);
+  ///   assert_eq!(chunked.get_interval_histogram(), whole.get_interval_histogram());
+  ///   assert_eq!(chunked.get_scores(), whole.get_scores());
+  /// }
+  /// ```
+  pub fn analyze_slice(&mut self, data:&[u8]) {
+    for value in data {
+      self.analyze(*value);
+    }
+  }
+
+  /// Analyze a slice of bytes, in order, calling `f` after each byte with the byte and the analysis so far.
+  /// A custom statistic can be computed in the same pass as the analysis, while each byte is still in cache.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  /// * `f` - The function called after each byte is analyzed, FnMut(u8, &Bytestat)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// let mut sum = 0u64;
+  /// let mut counters = Vec::new();
+  /// stats.analyze_with( b"The quick brown fox jumps over the lazy dog", |value, stats| {
+  ///   sum += value as u64;
+  ///   counters.push(stats.get_counter());
+  /// });
+  ///
+  /// assert_eq!(sum as f64, (stats.get_mean() * stats.get_counter() as f64).round());
+  /// assert_eq!(counters, (1..=43).collect::<Vec<u128>>());
+  /// ```
+  pub fn analyze_with<F: FnMut(u8, &Bytestat)>(&mut self, data:&[u8], mut f:F) {
+    for value in data {
+      self.analyze(*value);
+      f(*value, self);
+    }
+  }
+
+  /// Analyze the UTF-8 bytes of a string, in order.
+  /// Equivalent to calling `analyze_slice` on `s.as_bytes()`.
+  ///
+  /// # Arguments
+  ///
+  /// * `s` - The string to be analyzed, &str
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_str( "mot de passe: çàé" );
+  /// 
+  /// assert_eq!(stats.get_counter(), 20);
+  /// assert!(stats.get_entropy() > 3.0);
+  /// ```
+  pub fn analyze_str(&mut self, s:&str) {
+    self.analyze_slice(s.as_bytes());
+  }
+
+  /// Decode a hexadecimal string and analyze the bytes, in order.
+  /// Digits can be upper or lower case, whitespace is allowed between bytes.
+  /// The whole string is checked first, nothing is analyzed when it can not be decoded.
+  ///
+  /// # Arguments
+  ///
+  /// * `hex` - The hexadecimal string to be analyzed, &str
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::{Bytestat, BytestatError, HexError};
+  /// let mut decoded = Bytestat::new();
+  /// let mut direct = Bytestat::new();
+  /// decoded.analyze_hex( "deadBEEF" ).unwrap();
+  /// decoded.analyze_hex( "01 23\n45" ).unwrap();
+  /// direct.analyze_slice( &[0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45] );
+  /// 
+  /// assert_eq!(decoded.get_counter(), 7);
+  /// assert_eq!(decoded.get_serial_correlation(), direct.get_serial_correlation());
+  /// assert_eq!(decoded.get_distribution(), direct.get_distribution());
+  /// 
+  /// assert!(matches!(decoded.analyze_hex( "zz" ), Err(BytestatError::InvalidHex(HexError::InvalidDigit('z')))));
+  /// assert!(matches!(decoded.analyze_hex( "abc" ), Err(BytestatError::InvalidHex(HexError::OddLength))));
+  /// assert_eq!(decoded.get_counter(), 7);
+  /// ```
+  pub fn analyze_hex(&mut self, hex:&str) -> Result<(), BytestatError> {
+    decode_hex(hex, |_| ())?;
+    decode_hex(hex, |value| self.analyze(value))?;
+    Ok(())
+  }
+
+  /// Analyze every byte produced by a reader, in order, until end of stream.
+  /// The reader is consumed in chunks of 8 KiB.
+  /// Returns the number of bytes analyzed by this call.
+  /// On a read error, every byte read before the error has been analyzed and the error is returned as `BytestatError::Io`.
+  ///
+  /// # Arguments
+  ///
+  /// * `reader` - The source of the bytes to be analyzed, impl std::io::Read
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut from_reader = Bytestat::new();
+  /// let mut from_slice = Bytestat::new();
+  /// let data:Vec<u8> = (0..20000u32).map(|x| (x * 7 % 251) as u8).collect();
+  /// 
+  /// let count = from_reader.analyze_reader( data.as_slice() ).unwrap();
+  /// from_slice.analyze_slice( &data );
+  /// 
+  /// assert_eq!(count, 20000);
+  /// assert_eq!(from_reader.get_scores_array(), from_slice.get_scores_array());
+  /// ```
+  #[cfg(feature = "std")]
+  pub fn analyze_reader<R: std::io::Read>(&mut self, mut reader:R) -> Result<u128, BytestatError> {
+    let mut buffer = [0u8;8192];
+    let mut count:u128 = 0;
+    loop {
+      match reader.read(&mut buffer) {
+        Ok(0) => return Ok(count),
+        Ok(size) => {
+          self.analyze_slice(&buffer[..size]);
+          count += size as u128;
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+        Err(err) => return Err(BytestatError::Io(err))
+      }
+    }
+  }
+
+  /// Merge the analysis of the bytes that directly follow this analysis in the stream.
+  /// Afterward, the object is in the same state as if all bytes of `other` were analyzed by `self`, after its own.
+  /// The merge is exact: the intervals crossing the boundary between the two analyses are recorded,
+  /// using the position where each byte value first appeared in `other`.
+  /// Merging is not commutative, `other` must analyze the part of the stream located after `self`.
+  /// The configuration of `self` is kept.
+  /// The Monte Carlo estimate is only exact when the bytes count of `self` is a multiple of 6,
+  /// otherwise the incomplete point at the end of `self` is dropped.
+  /// The autocorrelation is only kept up to the smaller maximum lag of the two, and the sample is only complete
+  /// when the sample of `other` is at least as large as the missing part of the sample of `self`.
+  /// The gaps are only complete for the values tracked by both, see `track_gaps`.
+  /// Maurer's universal test is not merged, it only counts the bytes analyzed by `self`.
+  /// The stationarity score compares a different split of the set, see `get_stationarity_score`.
+  ///
+  /// # Arguments
+  ///
+  /// * `other` - The analysis of the bytes following the ones of `self`, &Bytestat
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// # #[cfg(feature = "alloc")] {
+  /// use libbytestat::Bytestat;
+  /// let mut whole = Bytestat::with_max_lag( 4 );
+  /// let mut head = Bytestat::with_max_lag( 4 );
+  /// let mut tail = Bytestat::with_max_lag( 4 );
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..100000).map(|_| get_random_byte()).collect();
+  /// 
+  /// whole.analyze_slice( &data );
+  /// head.analyze_slice( &data[..60000] );
+  /// tail.analyze_slice( &data[60000..] );
+  /// head.merge( &tail );
+  /// 
+  /// assert_eq!(head.get_scores_array(), whole.get_scores_array());
+  /// assert_eq!(head, whole);
+  /// assert_eq!(head.get_serial_correlation(), whole.get_serial_correlation());
+  /// assert_eq!(head.get_runs_score(), whole.get_runs_score());
+  /// for lag in 1..=4 {
+  ///   assert_eq!(head.get_autocorrelation( lag ), whole.get_autocorrelation( lag ));
+  /// }
+  /// # }
+  /// ```
+  pub fn merge(&mut self, other:&Bytestat) {
+    // The early part is the prefix closest to half of the joined set, among the prefixes known.
+    let half = (self.counter + other.counter) / 2;
+    let distance = |count:u128| count.abs_diff(half);
+    let candidates = [self.early_count, self.counter, self.counter + other.early_count];
+    let closest = (0..3).min_by_key(|x| distance(candidates[*x])).unwrap_or(0);
+    if closest > 0 {
+      for x in 0..256 {
+        self.early[x] = self.dist[x] + if closest == 2 { other.early[x] } else { 0 };
+      }
+      self.early_count = candidates[closest];
+    }
+    let walk = self.walk();
+    self.walk_max = self.walk_max.max(walk + other.walk_max);
+    self.walk_min = self.walk_min.min(walk + other.walk_min);
+    if let Some(other_first) = other.first_byte() {
+      if self.counter > 0 {
+        self.sum_products += self.previous as u128 * other_first as u128;
+        self.record_digraph(self.previous, other_first);
+        if (self.previous > 127) == (other_first > 127) {
+          self.runs -= 1;
+        }
+      }
+      // The run at the end of `self` goes on with the run at the start of `other`.
+      let joined = self.counter == 0 || self.previous == other_first;
+      let leading = if joined { self.run_length + other.leading_run } else { other.leading_run };
+      self.longest_run = self.longest_run.max(other.longest_run).max(leading);
+      if joined && self.leading_run == self.counter {
+        self.leading_run = leading;
+      }
+      self.run_length = if other.leading_run == other.counter { leading } else { other.run_length };
+      self.previous = other.previous;
+    }
+    self.sum += other.sum;
+    self.sum_squares += other.sum_squares;
+    self.sum_products += other.sum_products;
+    if other.counter > 0 {
+      self.point = other.point;
+      self.point_len = other.point_len;
+    }
+    self.points += other.points;
+    self.points_inside += other.points_inside;
+    self.above += other.above;
+    self.runs += other.runs;
+    self.ones += other.ones;
+    for x in 0..16 {
+      self.nibbles[x] += other.nibbles[x];
+    }
+    #[cfg(feature = "alloc")]
+    if other.counter > 0 {
+      self.lags.merge(&other.lags);
+    }
+    #[cfg(feature = "alloc")]
+    for (value, gaps) in &other.gaps {
+      for (gap, count) in gaps {
+        self.record_gap(*value, *gap as u128, *count);
+      }
+    }
+    #[cfg(feature = "alloc")]
+    if self.sample.len() < self.sample_size {
+      let missing = self.sample_size - self.sample.len();
+      self.sample.extend_from_slice(&other.sample[..missing.min(other.sample.len())]);
+    }
+    for x in 0..self.interval.len() {
+      let slot = x.min(self.max_interval as usize);
+      self.interval[slot] = self.interval[slot].saturating_add(other.interval[x]);
+      self.digraph[x] = self.digraph[x].saturating_add(other.digraph[x]);
+    }
+    for x in 0..256 {
+      if other.last[x] == 0 {
+        continue
+      }
+      if self.last[x] == 0 {
+        self.first[x] = self.counter + other.first[x];
+      }
+      else {
+        self.record_interval(self.counter + other.first[x] - self.last[x]);
+        #[cfg(feature = "alloc")]
+        self.record_gap(x as u8, self.counter + other.first[x] - self.last[x], 1);
+      }
+      self.last[x] = self.counter + other.last[x];
+      self.dist[x] += other.dist[x];
+    }
+    self.counter += other.counter;
+    self.distinct = self.dist.iter().filter(|x| **x > 0).count() as u16;
+    self.dist_squares = self.dist.iter().map(|x| x * x).sum();
+    if other.interval_top > self.interval_top {
+      self.interval_top = other.interval_top.min(self.max_interval);
+    }
+  }
+
+  /// Analyze a slice of bytes in parallel, with the `rayon` feature.
+  /// The slice is split in chunks of about 1 MiB, analyzed separately and merged in order.
+  /// The result is the same as `analyze_slice`, see `analyze_parallel_chunks`.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  #[cfg(feature = "rayon")]
+  pub fn analyze_parallel(&mut self, data:&[u8]) {
+    self.analyze_parallel_chunks(data, 1 << 20);
+  }
+
+  /// Analyze a slice of bytes in parallel, with the `rayon` feature, using chunks of `chunk_size` bytes.
+  /// Since `merge` is exact, the result is the same as `analyze_slice` for any chunk size.
+  /// The chunk size is rounded down to a multiple of 6, so the Monte Carlo points are not split between chunks.
+  /// Smaller chunks spread the work on more threads, but each chunk allocates its own interval table and costs one merge.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  /// * `chunk_size` - The number of bytes analyzed by each task, usize
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// # #[cfg(feature = "rayon")] {
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..4 << 20).map(|_| get_random_byte()).collect();
+  /// let mut parallel = Bytestat::new();
+  /// let mut sequential = Bytestat::new();
+  /// parallel.analyze_slice( &data[..5] );
+  /// parallel.analyze_parallel_chunks( &data[5..], 100000 );
+  /// sequential.analyze_slice( &data );
+  /// 
+  /// assert_eq!(parallel.get_scores(), sequential.get_scores());
+  /// assert_eq!(parallel.get_monte_carlo_pi(), sequential.get_monte_carlo_pi());
+  /// assert_eq!(parallel.get_runs_score(), sequential.get_runs_score());
+  /// # }
+  /// ```
+  #[cfg(feature = "rayon")]
+  pub fn analyze_parallel_chunks(&mut self, data:&[u8], chunk_size:usize) {
+    use rayon::prelude::*;
+
+    let aligned = ((6 - self.point_len as usize) % 6).min(data.len());
+    self.analyze_slice(&data[..aligned]);
+    let chunk_size = if chunk_size < 6 { 6 } else { chunk_size - chunk_size % 6 };
+    let template = &*self;
+    let analyzed = data[aligned..].par_chunks(chunk_size)
+      .map(|chunk| {
+        let mut stats = template.new_like();
+        stats.analyze_slice(chunk);
+        stats
+      })
+      .reduce(|| template.new_like(), |mut head, tail| {
+        head.merge(&tail);
+        head
+      });
+    self.merge(&analyzed);
+  }
+
+  // Expected probability of the byte value `x`, see `set_expected_distribution`.
+  fn expected_probability(&self, x:usize) -> f64 {
+    match &self.expected {
+      Some(probabilities) => probabilities[x],
+      None => 1.0 / 256.0
+    }
+  }
+
+  // Position of the random walk going up for each byte above 127 and down for each other byte.
+  fn walk(&self) -> i128 {
+    2 * self.above as i128 - self.counter as i128
+  }
+
+  fn first_byte(&self) -> Option<u8> {
+    (0..256).find(|x| self.first[*x] == 1).map(|x| x as u8)
+  }
+
+  fn compute_scores(&self) -> Scores {
+    self.compute_scores_for(self.counter)
+  }
+
+  // Compute the scores as if `count` bytes were recorded in the distribution and interval tables.
+  fn compute_scores_for(&self, count:u128) -> Scores {
+    self.compute_enabled_scores(count, self.enabled)
+  }
+
+  // Compute only the score at `index` in the order of the fields of `Scores`, the other scores are left at 0.0.
+  fn compute_one_score(&self, index:usize) -> Scores {
+    let mut enabled = [false;5];
+    enabled[index] = self.enabled[index];
+    self.compute_enabled_scores(self.counter, enabled)
+  }
+
+  // Compute the scores like `compute_scores_for`, with only the `enabled` scores.
+  fn compute_enabled_scores(&self, count:u128, enabled:[bool;5]) -> Scores {
+    let mut scores = Scores {
+      non_zero:0.0,
+      unique:0.0,
+      amplitude:0.0,
+      interval_continuity:0.0,
+      interval_amplitude:0.0,
+      total:0.0,
+    };
+
+    if count == 0 {
+      return scores
+    }
+
+    // Disabled scores are left at 0.0, see `BytestatBuilder`.
+    let [non_zero, unique, amplitude, interval_continuity, interval_amplitude] = enabled;
+
+    //1 of 5
+    if non_zero {
+      scores.non_zero = self.distinct as f64 / 256f64;
+    }
+
+    //2 of 5
+    if unique {
+      let mut dist_unique = 0;
+      let mut dist_sorted = self.dist;
+      dist_sorted.sort_unstable();
+      for x in 0..256 {
+        let same_as_previous = x > 0 && dist_sorted[x - 1] == dist_sorted[x];
+        let same_as_next = x < 255 && dist_sorted[x + 1] == dist_sorted[x];
+        if !same_as_previous && !same_as_next {
+          dist_unique += 1;
+        }
+      }
+      scores.unique = dist_unique as f64 / 256f64;
+    }
+
+    //3 of 5
+    if amplitude {
+      // 256 * sum(count²) - n² = 256² * variance, the coefficient of variation is sqrt(variance) / (n / 256).
+      let spread = (256 * self.dist_squares - count * count) as f64;
+      let variation = sqrt(spread) / count as f64;
+      scores.amplitude = if variation < 1.0 { 1.0 - variation } else { 0.0 };
+    }
+
+    if interval_continuity || interval_amplitude {
+      //4 of 5
+      // The significance threshold moves with the bytes count, this scan can not be maintained by `analyze`.
+      // It stops at the largest interval ever recorded.
+      let mut interval_max = u16::MIN;
+      let mut significant = 0;
+
+      for x in 1..=self.interval_top as usize {
+        if self.interval[x] as u128 > count / self.significance_divisor {
+          interval_max = x as u16;
+          significant += 1;
+        }
+      }
+
+      if interval_continuity {
+        scores.interval_continuity = significant.min(512) as f64 / 512f64;
+      }
+
+      //5 of 5
+      if interval_amplitude {
+        if interval_max > self.interval_amplitude_divisor {
+          interval_max = self.interval_amplitude_divisor;
+        }
+        scores.interval_amplitude = interval_max as f64 / self.interval_amplitude_divisor as f64;
+      }
+    }
+
+    //FINAL SCORE
+    scores.total = scores.non_zero * self.weights.non_zero;
+    scores.total += scores.unique * self.weights.unique;
+    scores.total += scores.amplitude * self.weights.amplitude;
+    scores.total += scores.interval_continuity * self.weights.interval_continuity;
+    scores.total += scores.interval_amplitude * self.weights.interval_amplitude;
+
+    // The weights of the enabled scores are scaled back to a sum of 100.
+    if enabled.contains(&false) {
+      let weights = [
+        self.weights.non_zero,
+        self.weights.unique,
+        self.weights.amplitude,
+        self.weights.interval_continuity,
+        self.weights.interval_amplitude
+      ];
+      let enabled:f64 = weights.iter().zip(enabled).filter(|(_, enabled)| *enabled).map(|(weight, _)| weight).sum();
+      scores.total = if enabled > 0.0 { scores.total * 100.0 / enabled } else { 0.0 };
+    }
+
+    scores
+  }
+
+  /// Generate the score based on distribution of unique bytes being present in the set.
+  /// The score is between 0.0 and 1.0. Any score lower than 1.0 should be considered problematic.
+  /// 
+  /// (unique byte present in set) / (maximum number of possible unique bytes, 256)
+  ///
+  /// The number of unique bytes present is kept current by `analyze`, the score is read without a scan
+  /// and can be polled after every byte.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..limit {
+  ///   let my_byte = get_random_byte();
+  ///   stats.analyze( my_byte );
+  ///   let present = stats.get_distribution().iter().filter(|count| **count > 0).count();
+  ///   assert_eq!(stats.get_score_non_zero(), present as f64 / 256.0);
+  /// }
+  /// 
+  /// stats.get_score_non_zero();
+  /// ```
+  pub fn get_score_non_zero(&self) -> f64 {
+    self.compute_one_score(0).non_zero
+  }
+
+  /// Generate the score based on the uniqueness of the bytes distribution in the set.
+  /// The score is between 0.0 and 1.0. Any score lower than 0.99 should be considered problematic.
+  /// 
+  /// (unique byte count in set) / (maximum number of possible unique bytes, 256)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..limit {
+  ///   let my_byte = get_random_byte();
+  ///   stats.analyze( my_byte );
+  /// }
+  /// 
+  /// stats.get_score_unique();
+  /// ```
+  ///
+  /// The unique byte counts are found without any allocation, this matches counting them with a map.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// use std::collections::HashMap;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let datasets:Vec<Vec<u8>> = vec![
+  ///   (0..100000).map(|_| get_random_byte()).collect(),
+  ///   (0..=255u8).cycle().take(10000).collect(),
+  ///   b"The quick brown fox jumps over the lazy dog".to_vec(),
+  ///   vec![0u8;1000],
+  ///   vec![],
+  /// ];
+  /// 
+  /// for data in datasets {
+  ///   let mut stats = Bytestat::new();
+  ///   stats.analyze_slice( &data );
+  ///   let mut counts:HashMap<u128, u32> = HashMap::new();
+  ///   for count in stats.get_distribution() {
+  ///     *counts.entry(count).or_insert(0) += 1;
+  ///   }
+  ///   let unique = counts.values().filter(|x| **x == 1).count();
+  ///   if !data.is_empty() {
+  ///     assert_eq!(stats.get_score_unique(), unique as f64 / 256.0);
+  ///   }
+  /// }
+  /// ```
+  pub fn get_score_unique(&self) -> f64 {
+    self.compute_one_score(1).unique
+  }
+
+  /// Generate the score based on the amplitude of the bytes distribution in the set, how flat the distribution is.
+  /// The score is between 0.0 and 1.0, 1.0 when every byte value has the same count.
+  /// It drops as the counts spread around their mean, down to 0.0 when the standard deviation reaches the mean.
+  /// A single missing byte value in an otherwise flat distribution gives a score of about 0.94.
+  /// Random data reaches 0.99 around 2.5 MB, any score lower than 0.99 should then be considered problematic.
+  /// 
+  /// 1 - (standard deviation of the counts) / (mean count), at least 0.0
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..limit {
+  ///   let my_byte = get_random_byte();
+  ///   stats.analyze( my_byte );
+  /// }
+  /// 
+  /// stats.get_score_amplitude();
+  /// ```
+  ///
+  /// The sum of the squared counts is maintained while analyzing, the score always matches the distribution.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..20000 {
+  ///   stats.analyze( get_random_byte() );
+  ///   if x % 97 == 0 {
+  ///     let dist = stats.get_distribution();
+  ///     let mean = dist.iter().sum::<u128>() as f64 / 256.0;
+  ///     let variance = dist.iter().map(|x| (*x as f64 - mean).powi(2)).sum::<f64>() / 256.0;
+  ///     let non_zero = dist.iter().filter(|x| **x > 0).count();
+  ///     assert!((stats.get_score_amplitude() - (1.0 - variance.sqrt() / mean).max(0.0)).abs() < 1e-9);
+  ///     assert_eq!(stats.get_score_non_zero(), non_zero as f64 / 256.0);
+  ///   }
+  /// }
+  /// ```
+  ///
+  /// A near uniform distribution scores high even with a missing byte value, a skewed one scores low.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut flat = Bytestat::new();
+  /// let mut missing = Bytestat::new();
+  /// let mut skewed = Bytestat::new();
+  /// let mut constant = Bytestat::new();
+  /// flat.analyze_slice( &(0..=255u8).cycle().take(25600).collect::<Vec<u8>>() );
+  /// missing.analyze_slice( &(1..=255u8).cycle().take(25500).collect::<Vec<u8>>() );
+  /// skewed.analyze_slice( &(0..=255u8).map(|x| x / 16).cycle().take(25600).collect::<Vec<u8>>() );
+  /// constant.analyze_slice( &[0u8;25600] );
+  ///
+  /// assert_eq!(flat.get_score_amplitude(), 1.0);
+  /// assert!((missing.get_score_amplitude() - (1.0 - 1.0 / 255f64.sqrt())).abs() < 1e-9);
+  /// assert_eq!(skewed.get_score_amplitude(), 0.0);
+  /// assert_eq!(constant.get_score_amplitude(), 0.0);
+  /// ```
+  pub fn get_score_amplitude(&self) -> f64 {
+    self.compute_one_score(2).amplitude
+  }
+
+  /// Generate the sub score based on the amplitude of the continuity of significant interval measurements.
+  /// The score is between 0 and 1. 
+  /// Any score lower than 0.99 should be considered problematic.
+  /// Every significant interval from 1 to the largest significant one counts once, a missing interval in between is not counted.
+  /// A set without any significant interval scores 0.0.
+  /// 
+  /// ( significant intervals in 1 ..= interval_largest, up to 512 ) / 512
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..limit {
+  ///   let my_byte = get_random_byte();
+  ///   stats.analyze( my_byte );
+  /// }
+  /// 
+  /// stats.get_score_amplitude();
+  /// ```
+  ///
+  /// Here, 9 comes back after 1, 2 and 4 bytes and 3 after 1 byte: the intervals 1, 2 and 4 are significant, 3 is missing.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( &[9, 9, 8, 9, 3, 3, 3, 9] );
+  /// 
+  /// assert_eq!(stats.get_score_interval_continuity(), 3.0 / 512.0);
+  /// assert_eq!(stats.get_score_interval_amplitude(), 4.0 / 512.0);
+  /// assert_eq!(Bytestat::from_slice( &[0, 1, 2, 3] ).get_score_interval_continuity(), 0.0);
+  /// ```
+  pub fn get_score_interval_continuity(&self) -> f64 {
+    self.compute_one_score(3).interval_continuity
+  }
+
+  /// Generate the score based on the amplitude of significant interval measurements relative to twice the range of byte.
+  /// The score is between 0.0 and 1.0. Any score lower than 1.0 should be considered problematic.
+  /// 
+  /// ( interval_largest, up to the divisor ) / ( divisor, 512 by default, see `set_interval_amplitude_divisor` )
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..limit {
+  ///   let my_byte = get_random_byte();
+  ///   stats.analyze( my_byte );
+  /// }
+  /// 
+  /// stats.get_score_interval_amplitude();
+  /// ```
+  pub fn get_score_interval_amplitude(&self) -> f64 {
+    self.compute_one_score(4).interval_amplitude
+  }
+
+  /// Generate the final score based on the 5 individual tests. 
+  /// Score between 0 and 100. 99 or lower is very problematic.
+  /// Each score is multiplied by its weight, 20 by default, see `set_weights`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let limit = 4096;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..limit {
+  ///   let my_byte = get_random_byte();
+  ///   stats.analyze( my_byte );
+  /// }
+  /// 
+  /// stats.get_score();
+  /// ```
+  ///
+  /// Before any byte is analyzed, every score is 0.0.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// 
+  /// assert_eq!(stats.get_scores_array(), [0.0;6]);
+  /// for x in [
+  ///   stats.get_score_non_zero(),
+  ///   stats.get_score_unique(),
+  ///   stats.get_score_amplitude(),
+  ///   stats.get_score_interval_continuity(),
+  ///   stats.get_score_interval_amplitude(),
+  ///   stats.get_score(),
+  ///   stats.get_entropy(),
+  ///   stats.get_chi_square(),
+  ///   stats.get_min_entropy(),
+  ///   stats.get_serial_correlation(),
+  ///   stats.get_monte_carlo_pi(),
+  ///   stats.get_runs_score(),
+  ///   stats.get_bit_balance(),
+  /// ] {
+  ///   assert!(x.is_finite());
+  /// }
+  /// ```
+  pub fn get_score(&self) -> f64 {
+    self.compute_scores().total
+  }
+
+  /// Generate the final score rounded up to the next integer, between 0 and 100.
+  /// This is the rounding of the crate documentation: any score above 99 rounds up to 100, 99.0 stays 99.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// // Only the non zero score is counted, the final score is (unique bytes present) * 100 / 256.
+  /// for (present, raw, rounded) in [(0, 0.0, 0), (1, 0.39, 1), (128, 50.0, 50), (129, 50.39, 51), (130, 50.78, 51),
+  ///   (253, 98.83, 99), (254, 99.22, 100), (255, 99.61, 100), (256, 100.0, 100)] {
+  ///   let mut stats = Bytestat::builder()
+  ///     .unique( false )
+  ///     .amplitude( false )
+  ///     .interval_continuity( false )
+  ///     .interval_amplitude( false )
+  ///     .build();
+  ///   stats.analyze_slice( &(0..present).map(|x| x as u8).collect::<Vec<u8>>() );
+  ///
+  ///   assert!((stats.get_score() - raw).abs() < 0.01);
+  ///   assert_eq!(stats.get_score_rounded(), rounded);
+  /// }
+  /// ```
+  pub fn get_score_rounded(&self) -> u8 {
+    let score = self.get_score();
+    if score >= 100.0 {
+      100
+    } else if score > 0.0 {
+      let whole = score as u8;
+      if (whole as f64) < score { whole + 1 } else { whole }
+    } else {
+      0
+    }
+  }
+
+  /// Check each of the five scores against its documented threshold, `PASS_THRESHOLDS`,
+  /// in the order of the fields of `Scores`. A score disabled by `BytestatBuilder` passes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..4 << 20).map(|_| get_random_byte()).collect();
+  /// let stats = Bytestat::from_slice( &data );
+  /// let mut lenient = Bytestat::builder().unique( false ).build();
+  /// lenient.analyze_slice( &data );
+  /// 
+  /// assert!(stats.get_score_unique() < Bytestat::PASS_THRESHOLDS[1]);
+  /// assert_eq!(stats.passes(), [true, false, true, true, true]);
+  /// assert!(!stats.all_pass());
+  /// assert!(lenient.all_pass());
+  /// assert_eq!(Bytestat::new().passes(), [false;5]);
+  /// ```
+  pub fn passes(&self) -> [bool;5] {
+    let scores = self.compute_scores();
+    let values = [scores.non_zero, scores.unique, scores.amplitude, scores.interval_continuity, scores.interval_amplitude];
+    let mut passes = [false;5];
+    for x in 0..5 {
+      passes[x] = !self.enabled[x] || values[x] >= Bytestat::PASS_THRESHOLDS[x];
+    }
+    passes
+  }
+
+  /// Check that every score passes its threshold, see `passes`.
+  pub fn all_pass(&self) -> bool {
+    !self.passes().contains(&false)
+  }
+
+  /// Check that the final score is at least `min_score` and that the set is significant, see `is_significant`.
+  /// A set too small for the score to be precise is never random, whatever its score.
+  ///
+  /// A `min_score` of 95.0 is recommended, lower than the cutoff of `get_confidence`, where only a score above 99 is `Good`.
+  /// The uniqueness score of random data keeps rising slowly with the size of the set, and varies by a point or two
+  /// from one set to the next: with the default configuration, random data scores about 97 once the set is significant,
+  /// usually above 99 past a few hundred MiB, with occasional dips to 98.
+  /// A gate in CI runs again and again, it needs that margin to not fail on good quality random data.
+  /// A `min_score` of 99.0 is about the `Good` cutoff, it suits sets well past significance where a rare failure is acceptable.
+  ///
+  /// # Arguments
+  ///
+  /// * `min_score` - The lowest passing final score, between 0 and 100, f64
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let mut seed:u64 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407); (seed >> 56) as u8 };
+  /// let mut chunk = vec![0u8;1 << 20];
+  /// chunk.fill_with(&mut get_random_byte);
+  /// stats.analyze_slice( &chunk );
+  /// assert!(!stats.is_random( 0.0 ));
+  ///
+  /// for x in 1..100 {
+  ///   chunk.fill_with(&mut get_random_byte);
+  ///   stats.analyze_slice( &chunk );
+  /// }
+  /// assert!(stats.is_significant());
+  /// assert!(stats.is_random( 95.0 ));
+  /// ```
+  pub fn is_random(&self, min_score:f64) -> bool {
+    self.is_significant() && self.get_score() >= min_score
+  }
+
+  /// Generate the final score from the three scores based on the bytes distribution only, between 0 and 100.
+  /// The interval scores are skipped, the weights of the distribution scores are scaled to sum to 100.
+  /// On a small set, not significant yet, the interval scores are meaningless: this is the score to show.
+  /// The scores disabled by `BytestatBuilder` stay disabled.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// let scores = stats.get_scores();
+  /// let expected = (scores.non_zero + scores.unique + scores.amplitude) * 100.0 / 3.0;
+  /// 
+  /// assert!(!stats.is_significant());
+  /// assert!((stats.get_score_distribution_only() - expected).abs() < 1e-9);
+  /// ```
+  pub fn get_score_distribution_only(&self) -> f64 {
+    let mut enabled = self.enabled;
+    enabled[3] = false;
+    enabled[4] = false;
+    self.compute_enabled_scores(self.counter, enabled).total
+  }
+
+  /// Generate the Shannon entropy of the bytes distribution, in bits per byte.
+  /// The entropy is between 0.0 and 8.0. Good quality random data should be very close to 8.0.
+  /// The entropy of an empty set is 0.0.
+  /// 
+  /// -sum( p(x) * log2(p(x)) ) where p(x) = (count of byte x) / (bytes count)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut uniform = Bytestat::new();
+  /// let mut constant = Bytestat::new();
+  /// uniform.analyze_slice( &(0..=255u8).cycle().take(4096).collect::<Vec<u8>>() );
+  /// constant.analyze_slice( &[42u8;4096] );
+  /// 
+  /// assert!((uniform.get_entropy() - 8.0).abs() < 1e-9);
+  /// assert_eq!(constant.get_entropy(), 0.0);
+  /// assert_eq!(Bytestat::new().get_entropy(), 0.0);
+  /// ```
+  pub fn get_entropy(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let mut entropy = 0.0;
+    for x in self.dist {
+      if x > 0 {
+        let p = x as f64 / self.counter as f64;
+        entropy -= p * log2(p);
+      }
+    }
+    entropy
+  }
+
+  /// Generate the chi-square statistic of the bytes distribution against a uniform distribution,
+  /// or the distribution set by `set_expected_distribution`.
+  /// The statistic has 255 degrees of freedom, random data should be around 255.
+  /// Against an expected distribution, the byte values with a probability of 0 are left out, the statistic is
+  /// infinite if one of them is present.
+  /// Much larger values mean the distribution is not uniform,
+  /// much smaller values mean it is too regular to be random.
+  /// The statistic of an empty set is 0.0.
+  /// 
+  /// sum( (count of byte x - expected)² / expected ) where expected = (bytes count) / 256
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..256000 {
+  ///   stats.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// let chi_square = stats.get_chi_square();
+  /// assert!(chi_square > 180.0 && chi_square < 340.0);
+  /// ```
+  pub fn get_chi_square(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let mut chi_square = 0.0;
+    for x in 0..256 {
+      let expected = self.counter as f64 * self.expected_probability(x);
+      if expected == 0.0 {
+        if self.dist[x] > 0 {
+          return f64::INFINITY
+        }
+        continue
+      }
+      let difference = self.dist[x] as f64 - expected;
+      chi_square += difference * difference / expected;
+    }
+    chi_square
+  }
+
+  /// Generate the p-value of the chi-square statistic, the probability for random data to get a larger statistic.
+  /// The p-value is between 0.0 and 1.0, values very close to 0.0 mean the distribution is not uniform,
+  /// values very close to 1.0 mean it is too regular to be random. Good quality random data should be far from both.
+  /// The statistic has 255 degrees of freedom, see `get_chi_square`. The p-value of an empty set is 1.0.
+  /// Against an expected distribution, the degrees of freedom are the byte values with a probability above 0, minus 1.
+  ///
+  /// The upper regularized incomplete gamma function Q(255 / 2, chi-square / 2) is computed
+  /// with its series below 129.5, and with its continued fraction above.
+  ///
+  /// # Examples
+  ///
+  /// Every byte value appears 4 times, except for pairs of byte values moved away from 4 by the given deviations.
+  ///
+  /// ```
+  /// # #[cfg(feature = "alloc")] {
+  /// use libbytestat::Bytestat;
+  /// fn with_deviations(deviations:&[(u8, usize)]) -> Bytestat {
+  ///   let mut counts = [4u8;256];
+  ///   let mut x = 0;
+  ///   for (deviation, pairs) in deviations {
+  ///     for _ in 0..*pairs {
+  ///       counts[x] += deviation;
+  ///       counts[x + 1] -= deviation;
+  ///       x += 2;
+  ///     }
+  ///   }
+  ///   let mut stats = Bytestat::new();
+  ///   for x in 0..256 {
+  ///     stats.analyze_slice( &vec![x as u8;counts[x] as usize] );
+  ///   }
+  ///   stats
+  /// }
+  /// 
+  /// let references = [
+  ///   (with_deviations( &[] ), 0.0, 1.0),
+  ///   (with_deviations( &[(2, 100)] ), 200.0, 0.995425444541952),
+  ///   (with_deviations( &[(3, 2), (2, 123)] ), 255.0, 0.488222521770406),
+  ///   (with_deviations( &[(4, 37), (2, 2)] ), 300.0, 0.0277275220539048),
+  ///   (with_deviations( &[(4, 43), (2, 3)] ), 350.0, 7.13274507266347e-5),
+  /// ];
+  /// for (stats, chi_square, pvalue) in references {
+  ///   assert_eq!(stats.get_chi_square(), chi_square);
+  ///   assert!((stats.get_chi_square_pvalue() - pvalue).abs() < 1e-9);
+  /// }
+  /// # }
+  /// ```
+  pub fn get_chi_square_pvalue(&self) -> f64 {
+    let values = (0..256).filter(|x| self.expected_probability(*x) > 0.0).count();
+    if values < 2 {
+      return 1.0
+    }
+    gamma_upper_regularized((values - 1) as f64 / 2.0, self.get_chi_square() / 2.0)
+  }
+
+  /// Generate the score of the stationarity of the bytes distribution, comparing the early part of the set to the rest.
+  /// A source can look random over the whole set while its distribution drifts over time.
+  /// The score is the p-value of the chi-square test of homogeneity between the two distributions,
+  /// between 0.0 and 1.0, the test fails below 0.01. The score is 1.0 until both parts contain bytes.
+  ///
+  /// The split can not follow the middle of the set as it grows, the distribution is only copied
+  /// each time the bytes count reaches a power of two: the early part holds between a half and all of the set.
+  /// When the bytes count is just past a power of two, the rest is small and the test is weak.
+  /// After `merge`, the early part is the known prefix of the joined sets closest to its half.
+  ///
+  /// sum( (a * sqrt(B / A) - b * sqrt(A / B))² / (a + b) ), with (byte values present - 1) degrees of freedom
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut drifting = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..100000 {
+  ///   drifting.analyze( if x < 50000 { get_random_byte() } else { 0 } );
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// assert!(drifting.get_stationarity_score() < 1e-9);
+  /// assert!(random.get_stationarity_score() > 0.01);
+  /// ```
+  pub fn get_stationarity_score(&self) -> f64 {
+    let early = self.early_count as f64;
+    let late = (self.counter - self.early_count) as f64;
+    if early == 0.0 || late == 0.0 {
+      return 1.0
+    }
+    let (early_weight, late_weight) = (sqrt(late / early), sqrt(early / late));
+    let mut statistic = 0.0;
+    let mut present = 0;
+    for x in 0..256 {
+      let a = self.early[x] as f64;
+      let b = self.dist[x].saturating_sub(self.early[x]) as f64;
+      if a + b > 0.0 {
+        let deviation = a * early_weight - b * late_weight;
+        statistic += deviation * deviation / (a + b);
+        present += 1;
+      }
+    }
+    if present < 2 {
+      return 1.0
+    }
+    gamma_upper_regularized((present - 1) as f64 / 2.0, statistic / 2.0)
+  }
+
+  /// Generate the Kolmogorov-Smirnov statistic of the bytes distribution against a uniform distribution,
+  /// or the distribution set by `set_expected_distribution`.
+  /// The statistic D is the largest difference between the proportion of bytes up to each value
+  /// and the expected proportion, (value + 1) / 256 when uniform, between 0.0 and 1.0.
+  /// Unlike the chi-square statistic, it is sensitive to the shape of the distribution,
+  /// like a set concentrated on a range of values.
+  /// The statistic of an empty set is 0.0.
+  ///
+  /// For n bytes, the distribution is not uniform with a significance of 5% when D > 1.36 / sqrt(n),
+  /// and of 1% when D > 1.63 / sqrt(n). These asymptotic bounds are conservative for the 256 discrete values.
+  ///
+  /// max( | (bytes count up to x) / n - (x + 1) / 256 | )
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::new();
+  /// let mut low_ramp = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..100000 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// low_ramp.analyze_slice( &(0..128u8).cycle().take(100000).collect::<Vec<u8>>() );
+  /// 
+  /// let bound = 1.36 / 100000f64.sqrt();
+  /// assert!(random.get_ks_statistic() < bound);
+  /// assert!((low_ramp.get_ks_statistic() - 0.5).abs() < 1e-3);
+  /// ```
+  pub fn get_ks_statistic(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let n = self.counter as f64;
+    let mut cumulated = 0;
+    let mut expected = 0.0;
+    let mut statistic = 0f64;
+    for x in 0..256 {
+      cumulated += self.dist[x];
+      expected += self.expected_probability(x);
+      let difference = (cumulated as f64 / n - expected).abs();
+      if difference > statistic {
+        statistic = difference;
+      }
+    }
+    statistic
+  }
+
+  /// Generate the min-entropy of the bytes distribution, in bits per byte.
+  /// The min-entropy is between 0.0 and 8.0, it is never larger than the Shannon entropy.
+  /// It is the conservative estimate to use when sizing cryptographic material.
+  /// The min-entropy of an empty set is 0.0.
+  /// 
+  /// -log2( p_max ) where p_max = (count of the most frequent byte) / (bytes count)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut uniform = Bytestat::new();
+  /// let mut dominant = Bytestat::new();
+  /// uniform.analyze_slice( &(0..=255u8).cycle().take(4096).collect::<Vec<u8>>() );
+  /// dominant.analyze_slice( &[0u8;900] );
+  /// dominant.analyze_slice( &(1..=100u8).collect::<Vec<u8>>() );
+  /// 
+  /// assert!((uniform.get_min_entropy() - 8.0).abs() < 1e-9);
+  /// assert!(dominant.get_min_entropy() < 0.2);
+  /// assert!(dominant.get_min_entropy() < dominant.get_entropy());
+  /// ```
+  pub fn get_min_entropy(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let mut max = 0;
+    for x in self.dist {
+      if x > max {
+        max = x;
+      }
+    }
+    -log2(max as f64 / self.counter as f64)
+  }
+
+  /// Generate the Rényi entropy of order `alpha` of the bytes distribution, in bits per byte, between 0.0 and 8.0.
+  /// The order 0 is the logarithm of the number of unique bytes present, the order 1 is the Shannon entropy,
+  /// see `get_entropy`, the order 2 is the collision entropy and an infinite order is the min-entropy,
+  /// see `get_min_entropy`. The entropy decreases as the order grows.
+  /// A negative order is treated as 0. The Rényi entropy of an empty set is 0.0.
+  /// 
+  /// log2( sum(p^alpha) ) / (1 - alpha)
+  ///
+  /// # Arguments
+  ///
+  /// * `alpha` - The order of the entropy, f64
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// // p = 1/2, 1/4, 1/4: sum(p²) = 3/8
+  /// let stats = Bytestat::from_slice( b"aabc" );
+  /// 
+  /// assert!((stats.get_renyi_entropy( 2.0 ) - (8.0f64 / 3.0).log2()).abs() < 1e-12);
+  /// assert!((stats.get_renyi_entropy( 0.0 ) - 3f64.log2()).abs() < 1e-12);
+  /// assert_eq!(stats.get_renyi_entropy( 1.0 ), stats.get_entropy());
+  /// assert_eq!(stats.get_renyi_entropy( f64::INFINITY ), stats.get_min_entropy());
+  /// assert!(stats.get_renyi_entropy( 2.0 ) < stats.get_entropy());
+  /// ```
+  pub fn get_renyi_entropy(&self, alpha:f64) -> f64 {
+    let alpha = alpha.max(0.0);
+    if self.counter == 0 {
+      return 0.0
+    }
+    if alpha == 1.0 {
+      return self.get_entropy()
+    }
+    if alpha.is_infinite() {
+      return self.get_min_entropy()
+    }
+    let mut sum = 0.0;
+    for x in self.dist {
+      if x > 0 {
+        sum += exp(alpha * ln(x as f64 / self.counter as f64));
+      }
+    }
+    log2(sum) / (1.0 - alpha)
+  }
+
+  /// Generate the Shannon entropy of the intervals, in bits per interval, how spread out the intervals are.
+  /// The interval of each byte since the previous occurrence of its value is counted, the first occurrences have
+  /// no interval and are not counted. Random data gives geometric intervals, about 9.44 bits.
+  /// Periodic data repeats a few intervals and gives a low entropy, 0.0 for a single interval.
+  /// The entropy is 0.0 before any interval is recorded.
+  ///
+  /// -sum( p * log2(p) ), p = (count of each interval) / (intervals count)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut periodic = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// periodic.analyze_slice( &(0..=255u8).cycle().take(100000).collect::<Vec<u8>>() );
+  /// for x in 0..100000 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// assert_eq!(periodic.get_interval_entropy(), 0.0);
+  /// assert!(random.get_interval_entropy() > 9.0);
+  /// ```
+  pub fn get_interval_entropy(&self) -> f64 {
+    let intervals = &self.interval[1..=self.interval_top as usize];
+    let total:u128 = intervals.iter().map(|x| *x as u128).sum();
+    if total == 0 {
+      return 0.0
+    }
+    let mut entropy = 0.0;
+    for x in intervals {
+      if *x > 0 {
+        let p = *x as f64 / total as f64;
+        entropy -= p * log2(p);
+      }
+    }
+    entropy
+  }
+
+  /// Generate the arithmetic mean of the byte values, as reported by the `ent` tool.
+  /// Good quality random data should be close to 127.5. The mean of an empty set is 0.0.
+  ///
+  /// sum(x) / n
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut text = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// text.analyze_slice( &b"The quick brown fox jumps over the lazy dog".repeat(100) );
+  /// for x in 0..65536 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// assert!(text.get_mean() < 100.0);
+  /// assert!((random.get_mean() - 127.5).abs() < 1.0);
+  /// assert!(text.get_score_mean() < 0.8);
+  /// assert!(random.get_score_mean() > 0.99);
+  /// ```
+  pub fn get_mean(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    self.sum as f64 / self.counter as f64
+  }
+
+  /// Generate the variance of the byte values.
+  /// Uniformly distributed bytes have a variance of (256² - 1) / 12, about 5461.25. The variance of an empty set is 0.0.
+  ///
+  /// sum(x²) / n - mean²
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &(0..=255u8).collect::<Vec<u8>>() );
+  /// 
+  /// assert_eq!(stats.get_variance(), 5461.25);
+  /// ```
+  pub fn get_variance(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let n = self.counter as f64;
+    let sum = self.sum as f64;
+    (n * self.sum_squares as f64 - sum * sum) / (n * n)
+  }
+
+  /// Generate the score based on the closeness of the mean to 127.5, between 0.0 and 1.0, see `get_mean`.
+  /// The score of an empty set is 0.0.
+  ///
+  /// 1 - |mean - 127.5| / 127.5
+  pub fn get_score_mean(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    1.0 - (self.get_mean() - 127.5).abs() / 127.5
+  }
+
+  /// Get the quantile `q` of the byte values, the smallest byte value whose cumulative count reaches q * (bytes count).
+  /// The quantile is exact, computed from the distribution. `q` is clamped between 0.0 and 1.0:
+  /// 0.0 gives the smallest byte value present, 1.0 the largest. The quantile of an empty set is 0.
+  ///
+  /// # Arguments
+  ///
+  /// * `q` - The fraction of the bytes at or below the quantile, f64
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[10, 20, 20, 30, 40, 50, 60, 200] );
+  ///
+  /// assert_eq!(stats.get_quantile( 0.0 ), 10);
+  /// assert_eq!(stats.get_quantile( 0.25 ), 20);
+  /// assert_eq!(stats.get_quantile( 0.75 ), 50);
+  /// assert_eq!(stats.get_quantile( 1.0 ), 200);
+  /// assert_eq!(Bytestat::new().get_quantile( 0.5 ), 0);
+  /// ```
+  pub fn get_quantile(&self, q:f64) -> u8 {
+    if self.counter == 0 {
+      return 0
+    }
+    let q = if q.is_nan() { 0.0 } else { q.clamp(0.0, 1.0) };
+    // The smallest whole count reaching q * (bytes count), at least one byte.
+    let exact = q * self.counter as f64;
+    let target = (exact as u128 + ((exact as u128 as f64) < exact) as u128).max(1);
+    let mut cumulated = 0;
+    for x in 0..256 {
+      cumulated += self.dist[x];
+      if cumulated >= target {
+        return x as u8
+      }
+    }
+    255
+  }
+
+  /// Get the median of the byte values, see `get_quantile`. The median of an empty set is 0.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &(0..=255u8).cycle().take(25600).collect::<Vec<u8>>() );
+  /// assert_eq!(stats.get_median(), 127);
+  ///
+  /// stats.analyze_slice( &[255;25600] );
+  /// assert_eq!(stats.get_median(), 255);
+  /// ```
+  pub fn get_median(&self) -> u8 {
+    self.get_quantile(0.5)
+  }
+
+  /// Generate the serial correlation coefficient between each byte and the next one.
+  /// The coefficient is between -1.0 and 1.0. Good quality random data should be very close to 0.0.
+  /// The last byte is paired with the first one, as done by the `ent` tool.
+  /// The coefficient is 0.0 when it is undefined: less than 2 bytes, or all bytes being identical.
+  /// 
+  /// ( n * sum(x[i] * x[i+1]) - sum(x)² ) / ( n * sum(x²) - sum(x)² )
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut ramp = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// ramp.analyze_slice( &(0..=255u8).cycle().take(65536).collect::<Vec<u8>>() );
+  /// for x in 0..65536 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// assert!(ramp.get_serial_correlation() > 0.9);
+  /// assert!(random.get_serial_correlation().abs() < 0.02);
+  /// ```
+  pub fn get_serial_correlation(&self) -> f64 {
+    let first = match self.first_byte() {
+      Some(first) => first,
+      None => return 0.0
+    };
+    let n = self.counter as f64;
+    let products = (self.sum_products + self.previous as u128 * first as u128) as f64;
+    let sum = self.sum as f64;
+    let denominator = n * self.sum_squares as f64 - sum * sum;
+    if self.counter < 2 || denominator == 0.0 {
+      return 0.0
+    }
+    (n * products - sum * sum) / denominator
+  }
+
+  /// Generate the autocorrelation coefficient between the bytes and the bytes `lag` positions later, with the `alloc` feature.
+  /// The coefficient is between -1.0 and 1.0, a periodic sequence peaks at its period.
+  /// Good quality random data should be very close to 0.0 at every lag.
+  /// Unlike `get_serial_correlation`, the sequence does not wrap around: only the n - lag pairs inside the set count.
+  ///
+  /// The lag must be between 1 and the `max_lag` given to `with_max_lag`, the history is not kept by `new()`.
+  /// The coefficient is 0.0 when it is undefined or not available: a lag out of these bounds,
+  /// no more bytes than the lag, or all bytes being identical.
+  ///
+  /// ( sum(x[i] * x[i+lag]) / (n - lag) - mean(x)² ) / variance(x)
+  ///
+  /// # Arguments
+  ///
+  /// * `lag` - The distance between the paired bytes, usize
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut periodic = Bytestat::with_max_lag( 8 );
+  /// periodic.analyze_slice( &[10u8, 200, 90].repeat(1000) );
+  ///
+  /// let peak = periodic.get_autocorrelation( 3 );
+  /// assert!((peak - 1.0).abs() < 1e-3);
+  /// for lag in [1, 2, 4, 5] {
+  ///   assert!(periodic.get_autocorrelation( lag ) < peak);
+  /// }
+  /// assert_eq!(periodic.get_autocorrelation( 9 ), 0.0);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_autocorrelation(&self, lag:usize) -> f64 {
+    if lag == 0 || lag > self.lags.max_lag() || self.counter <= lag as u128 {
+      return 0.0
+    }
+    let n = self.counter as f64;
+    let mean = self.sum as f64 / n;
+    let variance = self.sum_squares as f64 / n - mean * mean;
+    if variance <= 0.0 {
+      return 0.0
+    }
+    let products = self.lags.product(lag) as f64 / (n - lag as f64);
+    (products - mean * mean) / variance
+  }
+
+  /// Generate the score based on the distribution of the pairs of consecutive bytes being present in the set.
+  /// This is the score of `get_score_non_zero` applied to byte pairs, it is not part of the final score.
+  /// Bytes can each be uniformly distributed while following each other in a constrained order,
+  /// only a fraction of the possible pairs then appear.
+  /// Good quality random data reaches 1.0 after a few million bytes.
+  ///
+  /// (byte pairs present in set) / (maximum number of possible byte pairs, 65536)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut ramp = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// ramp.analyze_slice( &(0..=255u8).cycle().take(1 << 20).collect::<Vec<u8>>() );
+  /// for x in 0..1 << 20 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  ///
+  /// assert_eq!(ramp.get_score_non_zero(), 1.0);
+  /// assert_eq!(ramp.get_score_digraph(), 1.0 / 256.0);
+  /// assert!(random.get_score_digraph() > 0.99);
+  /// ```
+  pub fn get_score_digraph(&self) -> f64 {
+    self.digraph.iter().filter(|x| **x > 0).count() as f64 / 65536f64
+  }
+
+  /// Generate the score based on the dependence of each byte on the previous one, a first-order Markov structure.
+  /// The mutual information between the previous and the next byte of each pair of consecutive bytes
+  /// measures how much knowing the previous byte tells about the next one, see `get_digraph_counts`.
+  /// It is normalized by the entropy of the next byte: independent bytes score 1.0,
+  /// bytes fully determined by the previous one score 0.0.
+  /// A set without pairs, or of a single byte value, scores 1.0.
+  ///
+  /// The mutual information of a small set is overestimated, by about 46900 / (number of pairs) bits for random data:
+  /// good quality random data scores about 0.99 after a million bytes and gets closer to 1.0 as the set grows.
+  ///
+  /// 1 - (mutual information of previous and next byte) / (entropy of next byte)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::new();
+  /// let mut chained = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let mut value = 0u8;
+  /// for x in 0..1 << 20 {
+  ///   random.analyze( get_random_byte() );
+  ///   // Each byte is a function of the previous one, every byte value still appears as often.
+  ///   value = value.wrapping_mul(5).wrapping_add(1);
+  ///   chained.analyze( value );
+  /// }
+  ///
+  /// assert!(random.get_markov_score() > 0.99);
+  /// assert_eq!(chained.get_score_amplitude(), 1.0);
+  /// assert!(chained.get_markov_score() < 0.01);
+  /// ```
+  pub fn get_markov_score(&self) -> f64 {
+    let mut previous = [0u128;256];
+    let mut next = [0u128;256];
+    for (pair, count) in self.digraph.iter().enumerate() {
+      previous[pair >> 8] += *count as u128;
+      next[pair & 0xFF] += *count as u128;
+    }
+    let n:u128 = next.iter().sum();
+    if n == 0 {
+      return 1.0
+    }
+    let n = n as f64;
+    let mut entropy = 0.0;
+    for count in next.iter().filter(|x| **x > 0) {
+      let p = *count as f64 / n;
+      entropy -= p * log2(p);
+    }
+    if entropy <= 0.0 {
+      return 1.0
+    }
+    let mut information = 0.0;
+    for (pair, count) in self.digraph.iter().enumerate().filter(|(_, x)| **x > 0) {
+      let expected = previous[pair >> 8] as f64 * next[pair & 0xFF] as f64 / n;
+      information += *count as f64 * log2(*count as f64 / expected);
+    }
+    (1.0 - information / n / entropy).clamp(0.0, 1.0)
+  }
+
+  /// Generate the statistic of Maurer's universal test, with the `alloc` feature, the mean log2 distance between repeated blocks.
+  /// The bits of the set are split in blocks of the size given to `with_maurer_block_bits`, most significant bit first.
+  /// As specified by Maurer, the first 10 * 2^L blocks only initialize the table of the last position of each block,
+  /// the distance to the previous occurrence of each following block is then counted as a test block.
+  /// The statistic is 0.0 until a first test block, or when the test is not run.
+  ///
+  /// The statistic estimates the entropy per block, good quality random data should be close to the expected value:
+  ///
+  /// | L | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 |
+  /// |---|---|---|---|---|---|---|---|---|
+  /// | expected | 0.7326495 | 1.5374383 | 2.4016068 | 3.3112247 | 4.2534266 | 5.2177052 | 6.1962507 | 7.1836656 |
+  /// | variance | 0.690 | 1.338 | 1.901 | 2.358 | 2.705 | 2.954 | 3.125 | 3.238 |
+  ///
+  /// For L of 6 to 16, Maurer recommends at least 1000 * 2^L test blocks.
+  ///
+  /// sum( log2(block position - previous position of the same block) ) / (test blocks count)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::with_maurer_block_bits( 4 );
+  /// let mut ramp = Bytestat::with_maurer_block_bits( 4 );
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..100000 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// ramp.analyze_slice( &(0..=255u8).cycle().take(100000).collect::<Vec<u8>>() );
+  /// 
+  /// assert!((random.get_maurer_universal() - 3.3112247).abs() < 0.02);
+  /// assert!((ramp.get_maurer_universal() - 3.3112247).abs() > 0.1);
+  /// assert_eq!(Bytestat::new().get_maurer_universal(), 0.0);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_maurer_universal(&self) -> f64 {
+    let blocks = self.maurer.test_blocks();
+    if blocks == 0 {
+      return 0.0
+    }
+    self.maurer.sum() / blocks as f64
+  }
+
+  /// Generate the approximate entropy of the sample, with the `alloc` feature, for the embedding dimension `m` and the tolerance `r`.
+  /// Two windows of `m` bytes match when no pair of their bytes differ by more than `r`.
+  /// The approximate entropy is the mean of -ln( (matches of m + 1 bytes) / (matches of m bytes) ), following each window,
+  /// it measures how unpredictable the next byte is after a pattern was seen.
+  /// Repeating patterns give a value close to 0.0, good quality random data gives a high value,
+  /// bounded by the natural logarithm of the sample size.
+  /// Only the sample kept by `with_sample_size` is used, the value is 0.0 when the sample has no more than `m + 1` bytes.
+  ///
+  /// Every window is compared to every other window, the cost grows with (sample size)² * m:
+  /// keep the sample to a few thousand bytes, and `m` small.
+  ///
+  /// Φ(m) - Φ(m + 1), Φ(m) = mean( ln( (matches of each window of m bytes) / (windows count) ) )
+  ///
+  /// # Arguments
+  ///
+  /// * `m` - The number of bytes in each window, usize
+  /// * `r` - The largest difference between two matching bytes, u8
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut periodic = Bytestat::with_sample_size( 2000 );
+  /// let mut random = Bytestat::with_sample_size( 2000 );
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// periodic.analyze_slice( &[3u8, 141, 59, 26, 53].repeat(400) );
+  /// for x in 0..2000 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  ///
+  /// assert!(periodic.get_approx_entropy( 2, 0 ) < 0.01);
+  /// assert!(random.get_approx_entropy( 1, 0 ) > 1.0);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_approx_entropy(&self, m:usize, r:u8) -> f64 {
+    if self.sample.len() <= m + 1 {
+      return 0.0
+    }
+    approx_entropy_phi(&self.sample, m, r) - approx_entropy_phi(&self.sample, m + 1, r)
+  }
+
+  /// Generate the score of the block frequency test of NIST SP 800-22, with the `alloc` feature.
+  /// The sample kept by `with_sample_size` is split in blocks of `block_size` bytes,
+  /// the proportion of bits set in each block should be close to 1/2.
+  /// It catches a local bias, balanced out over the whole set, that the global metrics miss.
+  /// The score is the p-value of the chi-square of the proportions, between 0.0 and 1.0, the test fails below 0.01.
+  /// A partial block at the end of the sample is ignored, the score is 1.0 when the sample has no complete block.
+  ///
+  /// 4 * (bits per block) * sum( ((bits set in block) / (bits per block) - 1/2)² ), with (blocks count) degrees of freedom
+  ///
+  /// # Arguments
+  ///
+  /// * `block_size` - The number of bytes in each block, usize
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut skewed = Bytestat::with_sample_size( 10000 );
+  /// let mut random = Bytestat::with_sample_size( 10000 );
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..10000 {
+  ///   skewed.analyze( if x / 100 % 2 == 0 { 0x01 } else { 0xFE } );
+  ///   random.analyze( get_random_byte() );
+  /// }
+  ///
+  /// assert_eq!(skewed.get_bit_balance(), 1.0);
+  /// assert!(skewed.get_block_frequency_score( 100 ) < 1e-9);
+  /// assert!(random.get_block_frequency_score( 100 ) > 0.01);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_block_frequency_score(&self, block_size:usize) -> f64 {
+    if block_size == 0 || self.sample.len() < block_size {
+      return 1.0
+    }
+    let bits = 8.0 * block_size as f64;
+    let blocks = self.sample.chunks_exact(block_size);
+    let count = blocks.len();
+    let chi:f64 = blocks.map(|block| {
+      let ones:u32 = block.iter().map(|x| x.count_ones()).sum();
+      let deviation = ones as f64 / bits - 0.5;
+      deviation * deviation
+    }).sum();
+    gamma_upper_regularized(count as f64 / 2.0, 2.0 * bits * chi)
+  }
+
+  /// Generate the score of the discrete Fourier transform (spectral) test of NIST SP 800-22, with the `alloc` feature.
+  /// The bits of the sample kept by `with_sample_size` are mapped to +1 and -1, most significant bit first,
+  /// a periodic pattern shows as peaks in the spectrum of the walk.
+  /// The score is the p-value of the number of frequencies below the 95% peak threshold, between 0.0 and 1.0,
+  /// the test fails below 0.01. The score is 1.0 when the sample is empty.
+  ///
+  /// The transform is computed directly, the cost grows with (sample size * 8)² / 2:
+  /// a sample of a few thousand bytes is enough.
+  ///
+  /// d = (N1 - 0.95 * n / 2) / sqrt(n * 0.95 * 0.05 / 4), N1 = frequencies below sqrt(ln(1 / 0.05) * n), p = erfc(|d| / √2)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut periodic = Bytestat::with_sample_size( 1000 );
+  /// let mut random = Bytestat::with_sample_size( 1000 );
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..1000 {
+  ///   periodic.analyze( if x % 4 < 2 { 0x5A } else { get_random_byte() } );
+  ///   random.analyze( get_random_byte() );
+  /// }
+  ///
+  /// assert!(periodic.get_spectral_score() < 0.01);
+  /// assert!(random.get_spectral_score() > 0.01);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_spectral_score(&self) -> f64 {
+    let n = self.sample.len() * 8;
+    if n == 0 {
+      return 1.0
+    }
+    let walk:Vec<f64> = self.sample.iter()
+      .flat_map(|x| (0..8).rev().map(move |bit| if x >> bit & 1 == 1 { 1.0 } else { -1.0 }))
+      .collect();
+    let turn = 2.0 * core::f64::consts::PI / n as f64;
+    let roots:Vec<(f64, f64)> = (0..n).map(|x| sin_cos(turn * x as f64)).collect();
+    let threshold = sqrt(ln(1.0 / 0.05) * n as f64);
+    let mut below = 0;
+    for frequency in 0..n / 2 {
+      let mut real = 0.0;
+      let mut imaginary = 0.0;
+      let mut index = 0;
+      for x in &walk {
+        let (sin, cos) = roots[index];
+        real += x * cos;
+        imaginary -= x * sin;
+        index += frequency;
+        if index >= n {
+          index -= n;
+        }
+      }
+      if sqrt(real * real + imaginary * imaginary) < threshold {
+        below += 1;
+      }
+    }
+    let expected = 0.95 * n as f64 / 2.0;
+    let d = (below as f64 - expected) / sqrt(n as f64 * 0.95 * 0.05 / 4.0);
+    gamma_upper_regularized(0.5, d * d / 2.0)
+  }
+
+  /// Generate the compression ratio of the sample, with the `flate2` feature: the DEFLATE compressed size divided by the sample size.
+  /// Good quality random data does not compress, its ratio is close to 1.0 or slightly above,
+  /// structured data compresses and gets a much lower ratio.
+  /// Only the sample kept by `with_sample_size` is compressed, the ratio is 0.0 when the sample is empty.
+  /// The whole sample is compressed on each call, at the best compression level.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[cfg(feature = "flate2")] {
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::with_sample_size( 65536 );
+  /// let mut text = Bytestat::with_sample_size( 65536 );
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..100000 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// text.analyze_slice( &b"The quick brown fox jumps over the lazy dog. ".repeat(2000) );
+  ///
+  /// assert!(random.get_compression_ratio() > 0.99);
+  /// assert!(text.get_compression_ratio() < 0.05);
+  /// assert_eq!(Bytestat::new().get_compression_ratio(), 0.0);
+  /// # }
+  /// ```
+  #[cfg(feature = "flate2")]
+  pub fn get_compression_ratio(&self) -> f64 {
+    use std::io::Write;
+
+    if self.sample.is_empty() {
+      return 0.0
+    }
+    let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::best());
+    let compressed = encoder.write_all(&self.sample).and_then(|_| encoder.finish());
+    match compressed {
+      Ok(compressed) => compressed.len() as f64 / self.sample.len() as f64,
+      Err(_) => 0.0
+    }
+  }
+
+  /// Generate a Monte Carlo estimation of pi, as done by the `ent` tool.
+  /// Each group of 6 bytes is a point in a square, the first 3 bytes are the x coordinate and the last 3 bytes the y coordinate.
+  /// The proportion of points inside the inscribed quarter circle estimates pi / 4.
+  /// Good quality random data should be close to 3.14159, the precision grows with the square root of the number of points.
+  /// The estimation is 0.0 until a first point is complete.
+  /// 
+  /// 4 * (points inside the circle) / (points count)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// 
+  /// for x in 0..600000 {
+  ///   stats.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// let points = 100000f64;
+  /// assert!((stats.get_monte_carlo_pi() - std::f64::consts::PI).abs() < 10.0 / points.sqrt());
+  /// ```
+  pub fn get_monte_carlo_pi(&self) -> f64 {
+    if self.points == 0 {
+      return 0.0
+    }
+    4.0 * self.points_inside as f64 / self.points as f64
+  }
+
+  /// Generate the score of the runs test, on bytes above and below the middle value 127.5.
+  /// A run is a sequence of consecutive bytes on the same side of the middle value.
+  /// The score compares the observed number of runs to the number expected from random data with the same proportion of bytes on each side.
+  /// The score is between 0.0 and 1.0. Any score lower than 0.99 should be considered problematic.
+  /// Too few runs means bytes are clustered, too many runs means they alternate too regularly.
+  /// The score is 0.0 when all bytes are on the same side.
+  /// 
+  /// min(runs, expected) / max(runs, expected) where expected = 2 * above * below / (bytes count) + 1
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut ramp = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// ramp.analyze_slice( &(0..=255u8).cycle().take(65536).collect::<Vec<u8>>() );
+  /// for x in 0..65536 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// assert!(ramp.get_runs_score() < 0.1);
+  /// assert!(random.get_runs_score() > 0.99);
+  /// ```
+  pub fn get_runs_score(&self) -> f64 {
+    let below = self.counter - self.above;
+    if self.above == 0 || below == 0 {
+      return 0.0
+    }
+    let expected = 2.0 * self.above as f64 * below as f64 / self.counter as f64 + 1.0;
+    let runs = self.runs as f64;
+    if runs < expected { runs / expected } else { expected / runs }
+  }
+
+  /// Generate the score of the cumulative sums test of NIST SP 800-22, on bytes above and below the middle value 127.5.
+  /// Each byte above 127 is a step up of a random walk, each other byte a step down.
+  /// The score is the p-value of the largest distance from the start reached by the walk, relative to sqrt(bytes count),
+  /// between 0.0 and 1.0. A biased set drifts away, its score gets close to 0.0.
+  /// The score of random data is spread between 0.0 and 1.0, the test fails below 0.01.
+  /// The score of an empty set is 1.0.
+  ///
+  /// 1 - sum( Φ((4k + 1)z / √n) - Φ((4k - 1)z / √n) ) + sum( Φ((4k + 3)z / √n) - Φ((4k + 1)z / √n) ) where z = max(|walk|)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut drift = Bytestat::new();
+  /// let mut balanced = Bytestat::new();
+  /// let mut random = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// drift.analyze_slice( &[0xFFu8;10000] );
+  /// balanced.analyze_slice( &[0x00u8, 0xFF].repeat(5000) );
+  /// for x in 0..10000 {
+  ///   random.analyze( get_random_byte() );
+  /// }
+  /// 
+  /// assert!(drift.get_cusum_score() < 1e-9);
+  /// assert!(balanced.get_cusum_score() > 0.999);
+  /// assert!(random.get_cusum_score() > 0.01);
+  /// ```
+  pub fn get_cusum_score(&self) -> f64 {
+    if self.counter == 0 {
+      return 1.0
+    }
+    let z = self.walk_max.max(-self.walk_min) as f64;
+    let n = self.counter as f64;
+    let root = sqrt(n);
+    let mut sum = 0.0;
+    let mut k = ((-n / z + 1.0) / 4.0) as i64;
+    while k as f64 <= (n / z - 1.0) / 4.0 {
+      sum += normal_cdf((4 * k + 1) as f64 * z / root) - normal_cdf((4 * k - 1) as f64 * z / root);
+      k += 1;
+    }
+    let mut k = ((-n / z - 3.0) / 4.0) as i64;
+    while k as f64 <= (n / z - 1.0) / 4.0 {
+      sum -= normal_cdf((4 * k + 3) as f64 * z / root) - normal_cdf((4 * k + 1) as f64 * z / root);
+      k += 1;
+    }
+    (1.0 - sum).clamp(0.0, 1.0)
+  }
+
+  /// Generate the score based on the balance between set and unset bits.
+  /// The score is between 0.0 and 1.0, 1.0 when exactly half the bits are set and 0.0 when all bits are identical.
+  /// Any score lower than 0.99 should be considered problematic.
+  /// The score of an empty set is 0.0.
+  /// 
+  /// 1 - | 2 * (set bits count) / (bits count) - 1 |
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut balanced = Bytestat::new();
+  /// let mut skewed = Bytestat::new();
+  /// balanced.analyze_slice( &[0xAA;1000] );
+  /// skewed.analyze_slice( &[0xFF;1000] );
+  /// 
+  /// assert_eq!(balanced.get_bit_balance(), 1.0);
+  /// assert_eq!(skewed.get_bit_balance(), 0.0);
+  /// ```
+  pub fn get_bit_balance(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let ratio = self.ones as f64 / (self.counter * 8) as f64;
+    1.0 - (2.0 * ratio - 1.0).abs()
+  }
+
+  /// Get the number of bytes with each number of set bits, from 0 to 8.
+  /// The counts follow from the distribution, each byte value always has the same number of set bits.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( &[0x00, 0x01, 0x03, 0x30, 0xFF] );
+  ///
+  /// assert_eq!(stats.get_hamming_weights(), [1, 1, 2, 0, 0, 0, 0, 0, 1]);
+  /// ```
+  pub fn get_hamming_weights(&self) -> [u128;9] {
+    let mut weights = [0;9];
+    for (value, count) in self.dist.iter().enumerate() {
+      weights[value.count_ones() as usize] += count;
+    }
+    weights
+  }
+
+  /// Generate the score based on the number of set bits of each byte, the p-value of a chi-square test
+  /// of `get_hamming_weights` against the binomial distribution of random bytes, C(8, k) / 256 for k set bits.
+  /// The statistic has 8 degrees of freedom, the score is between 0.0 and 1.0 and spreads evenly over that range for random data.
+  /// Any score lower than 0.01 should be considered problematic. The score of an empty set is 1.0.
+  ///
+  /// Bytes can be balanced, with half of their bits set on average, and still have too few or too many bits
+  /// set each: the bit balance does not see it, this score does.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::new();
+  /// let mut four_bits = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// // The 70 byte values with exactly 4 set bits.
+  /// let balanced:Vec<u8> = (0..=255u8).filter(|x| x.count_ones() == 4).collect();
+  /// for x in 0..10000 {
+  ///   random.analyze( get_random_byte() );
+  ///   four_bits.analyze( balanced[get_random_byte() as usize % 70] );
+  /// }
+  ///
+  /// assert!(random.get_hamming_weight_score() > 0.01);
+  /// assert_eq!(four_bits.get_bit_balance(), 1.0);
+  /// assert!(four_bits.get_hamming_weight_score() < 1e-9);
+  /// ```
+  pub fn get_hamming_weight_score(&self) -> f64 {
+    if self.counter == 0 {
+      return 1.0
+    }
+    let n = self.counter as f64;
+    let mut chi_square = 0.0;
+    let mut binomial = 1.0;
+    for (k, count) in self.get_hamming_weights().iter().enumerate() {
+      let expected = n * binomial / 256.0;
+      let difference = *count as f64 - expected;
+      chi_square += difference * difference / expected;
+      binomial = binomial * (8 - k) as f64 / (k + 1) as f64;
+    }
+    gamma_upper_regularized(4.0, chi_square / 2.0).clamp(0.0, 1.0)
+  }
+
+  /// Generate the statistic of the poker test of FIPS 140-2, over the 4 bits groups of the set.
+  /// Each byte is split in its high and low nibbles, the statistic has 15 degrees of freedom.
+  /// For the 5000 nibbles of a 20000 bits FIPS sample, the test passes between 1.03 and 57.4.
+  /// The statistic of an empty set is 0.0.
+  ///
+  /// (16 / n) * sum( (count of nibble x)² ) - n where n = (nibbles count) = 2 * (bytes count)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::new();
+  /// let mut skewed = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..2500 {
+  ///   let byte = get_random_byte();
+  ///   random.analyze( byte );
+  ///   skewed.analyze( byte & 0x7F );
+  /// }
+  /// 
+  /// let statistic = random.get_poker_statistic();
+  /// assert!(statistic > 1.03 && statistic < 57.4);
+  /// assert!(skewed.get_poker_statistic() > 57.4);
+  /// ```
+  pub fn get_poker_statistic(&self) -> f64 {
+    if self.counter == 0 {
+      return 0.0
+    }
+    let n = 2.0 * self.counter as f64;
+    let squares:f64 = self.nibbles.iter().map(|x| *x as f64 * *x as f64).sum();
+    16.0 / n * squares - n
+  }
+
+  /// Generate the score of the poker test, the p-value of its statistic, between 0.0 and 1.0.
+  /// The score is the probability for random data to get a larger statistic, with 15 degrees of freedom.
+  /// Values very close to 0.0 mean the nibbles are not uniform, values very close to 1.0 mean they are too regular,
+  /// good quality random data should be far from both. The score of an empty set is 1.0.
+  ///
+  /// Q(15 / 2, statistic / 2), see `get_poker_statistic` and `get_chi_square_pvalue`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::new();
+  /// let mut skewed = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// for x in 0..2500 {
+  ///   let byte = get_random_byte();
+  ///   random.analyze( byte );
+  ///   skewed.analyze( byte & 0x7F );
+  /// }
+  /// 
+  /// let score = random.get_poker_score();
+  /// assert!(score > 0.01 && score < 0.99);
+  /// assert!(skewed.get_poker_score() < 1e-6);
+  /// ```
+  pub fn get_poker_score(&self) -> f64 {
+    gamma_upper_regularized(7.5, self.get_poker_statistic() / 2.0)
+  }
+
+  /// Get the length of the longest run of identical bytes in the set.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[1, 2, 2, 3] );
+  /// stats.analyze_slice( &[7u8;1000] );
+  /// stats.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// 
+  /// assert_eq!(stats.get_longest_run(), 1000);
+  /// ```
+  pub fn get_longest_run(&self) -> u128 {
+    self.longest_run
+  }
+
+  /// Generate the score based on the longest run of identical bytes, the probability for random data to get a run as long.
+  /// The score is between 0.0 and 1.0, it gets close to 0.0 when the longest run is improbably long for the bytes count.
+  /// A run of L bytes starts at a given position with a probability of (255 / 256) * (1 / 256)^(L - 1),
+  /// the score approximates the number of such runs in the set with a Poisson distribution.
+  /// The score of an empty set is 1.0.
+  ///
+  /// 1 - exp( -n * (255 / 256) * 256^-(L - 1) ) where L = (longest run)
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut random = Bytestat::new();
+  /// let mut repeated = Bytestat::new();
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let data:Vec<u8> = (0..100000).map(|_| get_random_byte()).collect();
+  /// random.analyze_slice( &data );
+  /// repeated.analyze_slice( &data[..50000] );
+  /// repeated.analyze_slice( &[0u8;8] );
+  /// repeated.analyze_slice( &data[50000..] );
+  /// 
+  /// assert!(random.get_score_runs_length() > 0.01);
+  /// assert!(repeated.get_score_runs_length() < 1e-9);
+  /// ```
+  pub fn get_score_runs_length(&self) -> f64 {
+    if self.longest_run == 0 {
+      return 1.0
+    }
+    let longest = self.longest_run.min(1 << 16) as f64;
+    let expected = self.counter as f64 * 255.0 / 256.0 * exp(-(longest - 1.0) * ln(256.0));
+    1.0 - exp(-expected)
+  }
+
+  /// Generate all the scores at once, as named fields.
+  /// Scores are computed on demand, from a shared reference.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// 
+  /// let scores = stats.get_scores();
+  /// let array = stats.get_scores_array();
+  /// assert_eq!(scores.non_zero, array[0]);
+  /// assert_eq!(scores.unique, array[1]);
+  /// assert_eq!(scores.amplitude, array[2]);
+  /// assert_eq!(scores.interval_continuity, array[3]);
+  /// assert_eq!(scores.interval_amplitude, array[4]);
+  /// assert_eq!(scores.total, array[5]);
+  /// ```
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// 
+  /// let shared = std::sync::Arc::new(stats);
+  /// let reader = std::sync::Arc::clone(&shared);
+  /// let threaded = std::thread::spawn(move || reader.get_score()).join().unwrap();
+  /// let first = &shared;
+  /// let second = &shared;
+  /// 
+  /// assert_eq!(first.get_scores(), second.get_scores());
+  /// assert_eq!(threaded, first.get_score());
+  /// ```
+  pub fn get_scores(&self) -> Scores {
+    self.compute_scores()
+  }
+
+  /// Capture the current scores, the same as `get_scores`, to follow how they evolve while more bytes arrive.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// let mut snapshots = Vec::new();
+  /// for x in 0..4 {
+  ///   stats.analyze_slice( &(0..=255u8).collect::<Vec<u8>>() );
+  ///   snapshots.push( stats.snapshot() );
+  /// }
+  /// 
+  /// assert_eq!(snapshots[3], stats.get_scores());
+  /// assert!(snapshots[0].interval_amplitude < snapshots[3].interval_amplitude);
+  /// ```
+  pub fn snapshot(&self) -> Scores {
+    self.compute_scores()
+  }
+
+  /// Get the scores captured since `enable_history`, with the `alloc` feature, along with the bytes count of each capture.
+  #[cfg(feature = "alloc")]
+  pub fn get_history(&self) -> &[(u128, Scores)] {
+    &self.history
+  }
+
+  /// Generate the change of each score since the previous call, to follow a live analysis.
+  /// The first call, or the first call after `reset`, returns the scores themselves.
+  /// The scores generated by this call are kept for the next one, the other methods do not change them.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( b"The quick brown fox" );
+  /// let first = stats.get_scores();
+  /// 
+  /// assert_eq!(stats.get_score_delta(), first);
+  /// stats.analyze_slice( b" jumps over the lazy dog" );
+  /// let second = stats.get_scores();
+  /// let delta = stats.get_score_delta();
+  /// assert_eq!(delta.non_zero, second.non_zero - first.non_zero);
+  /// assert_eq!(delta.total, second.total - first.total);
+  /// assert!(delta.non_zero > 0.0);
+  /// assert_eq!(stats.get_score_delta().total, 0.0);
+  /// ```
+  pub fn get_score_delta(&mut self) -> Scores {
+    let scores = self.compute_scores();
+    let delta = Scores {
+      non_zero:scores.non_zero - self.polled.non_zero,
+      unique:scores.unique - self.polled.unique,
+      amplitude:scores.amplitude - self.polled.amplitude,
+      interval_continuity:scores.interval_continuity - self.polled.interval_continuity,
+      interval_amplitude:scores.interval_amplitude - self.polled.interval_amplitude,
+      total:scores.total - self.polled.total,
+    };
+    self.polled = scores;
+    delta
+  }
+
+  /// Generate all the scores at once, in the order of the fields of `Scores`.
+  pub fn get_scores_array(&self) -> [f64;6] {
+    let scores = self.get_scores();
+    [
+      scores.non_zero,
+      scores.unique,
+      scores.amplitude,
+      scores.interval_continuity,
+      scores.interval_amplitude,
+      scores.total
+    ]
+  }
+
+  /// Generate every metric without parameters, as (name, value) pairs, with the `alloc` feature.
+  /// The scores come first, named as the fields of the JSON output, followed by the other metrics,
+  /// named as their `get_` methods. New metrics may be added at the end.
+  /// The metrics needing parameters or computed over the sample are left out:
+  /// `get_autocorrelation`, `get_approx_entropy`, `get_block_frequency_score`, `get_spectral_score`
+  /// and `get_compression_ratio`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// let metrics = stats.get_all_metrics();
+  /// let names:Vec<&str> = metrics.iter().map(|(name, _)| *name).collect();
+  ///
+  /// assert_eq!(names, [
+  ///   "non_zero", "unique", "amplitude", "interval_continuity", "interval_amplitude", "score",
+  ///   "entropy", "min_entropy", "chi_square", "chi_square_pvalue", "ks_statistic",
+  ///   "mean", "variance", "score_mean", "serial_correlation", "score_digraph", "maurer_universal",
+  ///   "monte_carlo_pi", "runs_score", "cusum_score", "bit_balance", "poker_statistic", "poker_score",
+  ///   "longest_run", "score_runs_length", "coverage", "stationarity_score", "interval_entropy",
+  ///   "markov_score", "hamming_weight_score", "median",
+  /// ]);
+  /// assert_eq!(metrics[..6].iter().map(|(_, value)| *value).collect::<Vec<f64>>(), stats.get_scores_array());
+  /// assert_eq!(metrics[6], ("entropy", stats.get_entropy()));
+  /// assert_eq!(metrics[25], ("coverage", stats.get_coverage()));
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_all_metrics(&self) -> Vec<(&'static str, f64)> {
+    let scores = self.get_scores();
+    vec![
+      ("non_zero", scores.non_zero),
+      ("unique", scores.unique),
+      ("amplitude", scores.amplitude),
+      ("interval_continuity", scores.interval_continuity),
+      ("interval_amplitude", scores.interval_amplitude),
+      ("score", scores.total),
+      ("entropy", self.get_entropy()),
+      ("min_entropy", self.get_min_entropy()),
+      ("chi_square", self.get_chi_square()),
+      ("chi_square_pvalue", self.get_chi_square_pvalue()),
+      ("ks_statistic", self.get_ks_statistic()),
+      ("mean", self.get_mean()),
+      ("variance", self.get_variance()),
+      ("score_mean", self.get_score_mean()),
+      ("serial_correlation", self.get_serial_correlation()),
+      ("score_digraph", self.get_score_digraph()),
+      ("maurer_universal", self.get_maurer_universal()),
+      ("monte_carlo_pi", self.get_monte_carlo_pi()),
+      ("runs_score", self.get_runs_score()),
+      ("cusum_score", self.get_cusum_score()),
+      ("bit_balance", self.get_bit_balance()),
+      ("poker_statistic", self.get_poker_statistic()),
+      ("poker_score", self.get_poker_score()),
+      ("longest_run", self.get_longest_run() as f64),
+      ("score_runs_length", self.get_score_runs_length()),
+      ("coverage", self.get_coverage()),
+      ("stationarity_score", self.get_stationarity_score()),
+      ("interval_entropy", self.get_interval_entropy()),
+      ("markov_score", self.get_markov_score()),
+      ("hamming_weight_score", self.get_hamming_weight_score()),
+      ("median", self.get_median() as f64),
+    ]
+  }
+
+  /// Generate all the scores at once as a string, in the order of the fields of `Scores`, separated by `seperator`.
+  /// The scores are computed once, and the string is allocated once.
+  ///
+  /// # Arguments
+  ///
+  /// * `seperator` - The separator between two scores, &str
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// # let mut seed:u32 = 1;
+  /// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+  /// let datasets:Vec<Vec<u8>> = vec![
+  ///   (0..100000).map(|_| get_random_byte()).collect(),
+  ///   b"The quick brown fox jumps over the lazy dog".to_vec(),
+  ///   vec![0u8;1000],
+  ///   vec![],
+  /// ];
+  /// 
+  /// for data in datasets {
+  ///   let mut stats = Bytestat::new();
+  ///   stats.analyze_slice( &data );
+  ///   let getters = [
+  ///     stats.get_score_non_zero(),
+  ///     stats.get_score_unique(),
+  ///     stats.get_score_amplitude(),
+  ///     stats.get_score_interval_continuity(),
+  ///     stats.get_score_interval_amplitude(),
+  ///     stats.get_score(),
+  ///   ];
+  ///   let expected:Vec<String> = getters.iter().map(|x| x.to_string()).collect();
+  ///   assert_eq!(stats.get_scores_string( "\n" ), expected.join( "\n" ));
+  ///   assert_eq!(stats.get_scores_string( ", " ), expected.join( ", " ));
+  /// }
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_scores_string(&self, seperator:&str) -> String {
+    use core::fmt::Write;
+
+    let scores = self.get_scores_array();
+    let mut answer = String::with_capacity(scores.len() * 24 + (scores.len() - 1) * seperator.len());
+    for (x, score) in scores.iter().enumerate() {
+      if x > 0 {
+        answer.push_str( seperator );
+      }
+      let _ = write!(answer, "{}", score);
+    }
+    answer
+  }
+
+  /// Generate the scores as a CSV row, in the same order as `get_scores_string`.
+  /// Each row ends with a newline, the header row is optional.
+  ///
+  /// # Arguments
+  ///
+  /// * `with_header` - Start with a row naming the columns, bool
+  /// 
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// 
+  /// let csv = stats.get_scores_csv( true );
+  /// let mut lines = csv.lines();
+  /// assert_eq!(lines.next(), Some("non_zero,unique,amplitude,interval_continuity,interval_amplitude,score"));
+  /// let values:Vec<f64> = lines.next().unwrap().split(',').map(|x| x.parse().unwrap()).collect();
+  /// assert_eq!(values, stats.get_scores_array());
+  /// assert_eq!(stats.get_scores_csv( false ), csv.lines().nth(1).unwrap().to_string() + "\n");
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_scores_csv(&self, with_header:bool) -> String {
+    let mut answer = String::from("");
+    if with_header {
+      answer.push_str("non_zero,unique,amplitude,interval_continuity,interval_amplitude,score\n");
+    }
+    answer.push_str( self.get_scores_string(",").as_str() );
+    answer.push('\n');
+    answer
+  }
+
+  /// Draw the bytes distribution as a bar chart, with the `alloc` feature, for a quick look in a terminal.
+  /// Each of the 256 lines shows a byte value in hexadecimal and a bar of block characters, in eighths of a column.
+  /// The bar of the most frequent byte is `width` columns long, the others are scaled to it.
+  /// Before any byte is analyzed, every bar is empty.
+  ///
+  /// # Arguments
+  ///
+  /// * `width` - The number of columns of the longest bar, usize
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( b"hello world" );
+  /// let chart = stats.render_histogram( 40 );
+  /// let lines:Vec<&str> = chart.lines().collect();
+  /// 
+  /// assert_eq!(lines.len(), 256);
+  /// assert_eq!(lines[b'l' as usize], format!("6c {}", "█".repeat(40)));
+  /// assert_eq!(lines[b'o' as usize], format!("6f {}▋", "█".repeat(26)));
+  /// assert_eq!(lines[0], "00 ");
+  /// let widest = lines.iter().max_by_key(|line| line.chars().count()).unwrap();
+  /// assert!(widest.starts_with("6c"));
+  /// assert!(Bytestat::new().render_histogram( 40 ).lines().all(|line| line.len() == 3));
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn render_histogram(&self, width:usize) -> String {
+    use core::fmt::Write;
+
+    const EIGHTHS:[char;8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let max = self.dist.iter().copied().max().unwrap_or(0);
+    let mut answer = String::with_capacity(256 * (4 + width * 3));
+    for (value, count) in self.dist.iter().enumerate() {
+      let _ = write!(answer, "{:02x} ", value);
+      let eighths = (count * width as u128 * 8 + max / 2).checked_div(max).unwrap_or(0);
+      for _ in 0..eighths / 8 {
+        answer.push('█');
+      }
+      let rest = (eighths % 8) as usize;
+      if rest > 0 {
+        answer.push(EIGHTHS[rest]);
+      }
+      answer.push('\n');
+    }
+    answer
+  }
+
+  /// Explain the final score, with the `alloc` feature: for each score, its points in the final score,
+  /// out of its weight, and what keeps it from reaching its weight.
+  /// The last line names the score losing the most points.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &(0..244u8).cycle().take(244 * 1000).collect::<Vec<u8>>() );
+  /// let explanation = stats.explain();
+  ///
+  /// assert!(explanation.starts_with("244000 samples\n"));
+  /// assert!(explanation.contains("non zero: 95.31%, 19.06 of 20.00 points, 12 byte values never appeared\n"));
+  /// assert!(explanation.contains("unique: 0.00%, 0.00 of 20.00 points, 256 byte values share their count with another one\n"));
+  /// assert!(explanation.ends_with("most points lost by unique\n"));
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn explain(&self) -> String {
+    use core::fmt::Write;
+
+    let scores = self.compute_scores();
+    let weights = [
+      self.weights.non_zero,
+      self.weights.unique,
+      self.weights.amplitude,
+      self.weights.interval_continuity,
+      self.weights.interval_amplitude
+    ];
+    let enabled:f64 = weights.iter().zip(self.enabled).filter(|(_, enabled)| *enabled).map(|(weight, _)| weight).sum();
+    let threshold = self.counter / self.significance_divisor;
+    let (mut significant, mut interval_max) = (0, 0);
+    for x in 1..=self.interval_top as usize {
+      if self.interval[x] as u128 > threshold {
+        significant += 1;
+        interval_max = x;
+      }
+    }
+    let shared = 256 - (scores.unique * 256.0) as u32;
+    let causes = [
+      if self.distinct == 256 { String::from("every byte value appeared") }
+      else { format!("{} byte values never appeared", 256 - self.distinct) },
+      if shared == 0 { String::from("every byte value has a count of its own") }
+      else { format!("{} byte values share their count with another one", shared) },
+      if self.counter == 0 { String::from("no byte analyzed") }
+      else { format!("the standard deviation of the counts is {:.2}% of their mean",
+        sqrt((256 * self.dist_squares - self.counter * self.counter) as f64) / self.counter as f64 * 100.0) },
+      format!("{} significant intervals out of the 512 needed, an interval is significant above {} occurrences",
+        significant, threshold),
+      format!("the largest significant interval is {} out of the {} needed", interval_max, self.interval_amplitude_divisor),
+    ];
+    let names = ["non zero", "unique", "amplitude", "interval continuity", "interval amplitude"];
+    let values = [scores.non_zero, scores.unique, scores.amplitude, scores.interval_continuity, scores.interval_amplitude];
+
+    let mut answer = String::new();
+    let _ = writeln!(answer, "{} samples", self.counter);
+    let mut lost = (0.0, None);
+    for x in 0..5 {
+      if !self.enabled[x] {
+        let _ = writeln!(answer, "{}: disabled", names[x]);
+        continue
+      }
+      let weight = weights[x] * 100.0 / enabled;
+      let points = values[x] * weight;
+      let _ = writeln!(answer, "{}: {:.2}%, {:.2} of {:.2} points, {}", names[x], values[x] * 100.0, points, weight, causes[x]);
+      if weight - points > lost.0 {
+        lost = (weight - points, Some(names[x]));
+      }
+    }
+    let _ = match lost.1 {
+      Some(name) => writeln!(answer, "score: {:.2}, most points lost by {}", scores.total, name),
+      None => writeln!(answer, "score: {:.2}, no points lost", scores.total),
+    };
+    answer
+  }
+
+  /// Generate the scores and the bytes count as a JSON object.
+  /// Requires the `serde` feature.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[cfg(feature = "serde")] {
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+  /// 
+  /// let json:serde_json::Value = serde_json::from_str( &stats.get_scores_json() ).unwrap();
+  /// let scores = stats.get_scores();
+  /// assert_eq!(json["non_zero"], scores.non_zero);
+  /// assert_eq!(json["unique"], scores.unique);
+  /// assert_eq!(json["amplitude"], scores.amplitude);
+  /// assert_eq!(json["interval_continuity"], scores.interval_continuity);
+  /// assert_eq!(json["interval_amplitude"], scores.interval_amplitude);
+  /// assert_eq!(json["score"], scores.total);
+  /// assert_eq!(json["counter"], 43);
+  /// # }
+  /// ```
+  #[cfg(feature = "serde")]
+  pub fn get_scores_json(&self) -> String {
+    #[derive(serde::Serialize)]
+    struct ScoresJson {
+      #[serde(flatten)]
+      scores:Scores,
+      counter:u128,
+    }
+    let json = ScoresJson {
+      scores:self.get_scores(),
+      counter:self.counter,
+    };
+    serde_json::to_string(&json).unwrap_or_default()
+  }
+
+  /// Get the number of bytes analyzed so far.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[1u8;1000] );
+  /// stats.analyze( 2 );
+  /// 
+  /// assert_eq!(stats.get_counter(), 1001);
+  /// ```
+  pub fn get_counter(&self) -> u128 {
+    self.counter
+  }
+
+  /// Check if enough bytes were analyzed for the scores to be precise.
+  /// The set is significant when it contains at least 256 * (significance divisor) * 100 bytes,
+  /// 104857600 bytes with the default divisor.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.set_significance_divisor( 1 );
+  /// stats.analyze_slice( &[0u8;25599] );
+  /// assert!(!stats.is_significant());
+  /// 
+  /// stats.analyze( 0 );
+  /// assert!(stats.is_significant());
+  /// ```
+  pub fn is_significant(&self) -> bool {
+    self.counter >= 256 * self.significance_divisor * 100
+  }
+
+  /// Get the number of bytes still to be analyzed for the set to be significant, 0 once it is, see `is_significant`.
+  /// The fraction of the way already done is `get_counter` / (`get_counter` + `bytes_until_significant`).
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.set_significance_divisor( 2 );
+  /// stats.analyze_slice( &[0u8;10000] );
+  /// assert_eq!(stats.bytes_until_significant(), 256 * 2 * 100 - 10000);
+  ///
+  /// stats.analyze_slice( &[0u8;41199] );
+  /// assert_eq!(stats.bytes_until_significant(), 1);
+  /// stats.analyze( 0 );
+  /// assert_eq!(stats.bytes_until_significant(), 0);
+  /// assert!(stats.is_significant());
+  /// ```
+  pub fn bytes_until_significant(&self) -> u128 {
+    (256 * self.significance_divisor * 100).saturating_sub(self.counter)
+  }
+
+  /// Interpret the final score, once enough bytes were analyzed.
+  /// The set is `Insufficient` until it is significant, see `is_significant`.
+  /// Afterward, the band follows `get_score_rounded`: 100 is `Good`, 99 is `Marginal` and 98 or lower is `Poor`.
+  /// Any score above 99 is then `Good`, a score above 98 up to 99 is `Marginal`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::{Bytestat, Confidence, Weights};
+  /// let ramp:Vec<u8> = (0..=255u8).cycle().take(25600).collect();
+  /// // Only the score of bytes present counts, the ramp has all of them but no unique count.
+  /// let bands = [
+  ///   (1000, 100.0, Confidence::Insufficient),
+  ///   (25600, 100.0, Confidence::Good),
+  ///   (25600, 99.6, Confidence::Good),
+  ///   (25600, 99.3, Confidence::Good),
+  ///   (25600, 99.0, Confidence::Marginal),
+  ///   (25600, 98.5, Confidence::Marginal),
+  ///   (25600, 97.5, Confidence::Poor),
+  ///   (25600, 50.0, Confidence::Poor),
+  /// ];
+  /// for (size, present, confidence) in bands {
+  ///   let weights = Weights {
+  ///     non_zero:present,
+  ///     unique:100.0 - present,
+  ///     amplitude:0.0,
+  ///     interval_continuity:0.0,
+  ///     interval_amplitude:0.0,
+  ///   };
+  ///   let mut stats = Bytestat::with_weights( weights ).unwrap();
+  ///   stats.set_significance_divisor( 1 );
+  ///   stats.analyze_slice( &ramp[..size] );
+  ///   assert_eq!(stats.get_confidence(), confidence);
+  /// }
+  /// ```
+  pub fn get_confidence(&self) -> Confidence {
+    if !self.is_significant() {
+      return Confidence::Insufficient
+    }
+    match self.get_score_rounded() {
+      100 => Confidence::Good,
+      99 => Confidence::Marginal,
+      _ => Confidence::Poor,
+    }
+  }
+
+  /// Generate the final result of the analysis and drop the analysis, with its tables.
+  /// The report only holds the bytes count, the scores and the confidence, a few dozen bytes:
+  /// the reports of thousands of sets can be kept at little cost.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::{Bytestat, Confidence};
+  /// let data = b"The quick brown fox jumps over the lazy dog".repeat(100);
+  /// let reports:Vec<_> = (1..=3).map(|x| Bytestat::from_slice( &data[..x * 1000] ).into_report()).collect();
+  /// 
+  /// assert_eq!(reports[2].counter, 3000);
+  /// assert_eq!(reports[2].scores, Bytestat::from_slice( &data[..3000] ).get_scores());
+  /// assert_eq!(reports[2].confidence, Confidence::Insufficient);
+  /// assert!(std::mem::size_of::<libbytestat::Report>() < 128);
+  /// ```
+  pub fn into_report(self) -> Report {
+    Report {
+      counter:self.counter,
+      scores:self.compute_scores(),
+      confidence:self.get_confidence(),
+    }
+  }
+
+  /// Get the number of occurrences of each byte value, indexed by byte value.
+  /// The scores are not updated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[7, 7, 7, 42, 255] );
+  /// 
+  /// let dist = stats.get_distribution();
+  /// assert_eq!(dist[7], 3);
+  /// assert_eq!(dist[42], 1);
+  /// assert_eq!(dist[255], 1);
+  /// assert_eq!(dist.iter().sum::<u128>(), 5);
+  /// ```
+  pub fn get_distribution(&self) -> [u128;256] {
+    self.dist
+  }
+
+  /// Get the sample, the first bytes analyzed, with the `alloc` feature, see `with_sample_size`.
+  #[cfg(feature = "alloc")]
+  pub fn get_sample(&self) -> &[u8] {
+    &self.sample
+  }
+
+  /// Get the interval table, the count of each interval between two occurrences of the same byte value, of any value.
+  /// The table has 65536 entries, index `k` holds the number of times a byte value came back after `k` bytes.
+  /// Index 0 is always 0, and intervals of 65535 bytes or more are all counted at index 65535.
+  /// Each count saturates at u32::MAX.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[1, 2, 3, 1, 2, 3, 1, 4, 4] );
+  /// 
+  /// let histogram = stats.get_interval_histogram();
+  /// assert_eq!(histogram.len(), 65536);
+  /// assert_eq!(histogram[3], 4);
+  /// assert_eq!(histogram[1], 1);
+  /// assert_eq!(histogram.iter().sum::<u32>(), 5);
+  /// ```
+  pub fn get_interval_histogram(&self) -> &[u32] {
+    &self.interval[..]
+  }
+
+  /// Get the number of occurrences of each pair of consecutive bytes, with the `alloc` feature.
+  /// The table has 65536 entries, the pair of a byte `previous` followed by a byte `value` is at `(previous << 8) | value`.
+  /// The first byte has no predecessor, the set does not wrap around: n bytes make n - 1 pairs.
+  /// Each count saturates at u32::MAX.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[1, 2, 1, 2, 3] );
+  ///
+  /// let digraphs = stats.get_digraph_counts();
+  /// assert_eq!(digraphs[(1 << 8) | 2], 2);
+  /// assert_eq!(digraphs[(2 << 8) | 1], 1);
+  /// assert_eq!(digraphs[(2 << 8) | 3], 1);
+  /// assert_eq!(digraphs.iter().sum::<u128>(), 4);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_digraph_counts(&self) -> Box<[u128]> {
+    self.digraph.iter().map(|x| *x as u128).collect()
+  }
+
+  /// Get the gaps recorded between the occurrences of `value` since `track_gaps`, with the `alloc` feature.
+  /// Each gap length comes with the number of times it was found, by increasing gap length.
+  /// Gaps of 65535 bytes or more are all recorded as 65535, the first occurrence of a value has no gap.
+  /// The gaps of a value that is not tracked are not recorded, the distribution is then empty.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - The byte value whose gaps are returned, u8
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.track_gaps( 0 );
+  /// stats.analyze_slice( &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9].repeat(100) );
+  /// stats.analyze_slice( &[0, 0] );
+  /// 
+  /// assert_eq!(stats.get_gap_distribution( 0 ), [(1, 1), (10, 100)]);
+  /// assert_eq!(stats.get_gap_distribution( 1 ), []);
+  /// ```
+  #[cfg(feature = "alloc")]
+  pub fn get_gap_distribution(&self, value:u8) -> Vec<(u16, u128)> {
+    match self.gaps.iter().find(|(tracked, _)| *tracked == value) {
+      Some((_, gaps)) => gaps.iter().map(|(gap, count)| (*gap, *count)).collect(),
+      None => Vec::new()
+    }
+  }
+
+  /// Get the most frequent byte value and its count, `None` before any byte is analyzed.
+  /// On a tie, the lowest byte value is returned.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// assert_eq!(stats.get_most_frequent_byte(), None);
+  /// 
+  /// stats.analyze_slice( &(0..=255u8).collect::<Vec<u8>>() );
+  /// stats.analyze_slice( &[0x00;10] );
+  /// stats.analyze_slice( &[0x80, 0x80] );
+  /// assert_eq!(stats.get_most_frequent_byte(), Some((0x00, 11)));
+  /// assert_eq!(stats.get_least_frequent_byte(), Some((0x01, 1)));
+  /// ```
+  pub fn get_most_frequent_byte(&self) -> Option<(u8, u128)> {
+    if self.counter == 0 {
+      return None
+    }
+    let mut most = 0;
+    for x in 1..256 {
+      if self.dist[x] > self.dist[most] {
+        most = x;
+      }
+    }
+    Some((most as u8, self.dist[most]))
+  }
+
+  /// Get the least frequent byte value and its count, `None` before any byte is analyzed.
+  /// Byte values never seen count as 0 occurrence.
+  /// On a tie, the lowest byte value is returned.
+  pub fn get_least_frequent_byte(&self) -> Option<(u8, u128)> {
+    if self.counter == 0 {
+      return None
+    }
+    let mut least = 0;
+    for x in 1..256 {
+      if self.dist[x] < self.dist[least] {
+        least = x;
+      }
+    }
+    Some((least as u8, self.dist[least]))
+  }
+
+  /// Get the fraction of the 256 byte values seen at least once, between 0.0 and 1.0.
+  /// This is the same value as `get_score_non_zero`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &(0..64u8).collect::<Vec<u8>>() );
+  /// 
+  /// assert_eq!(stats.get_coverage(), 0.25);
+  /// ```
+  pub fn get_coverage(&self) -> f64 {
+    self.distinct as f64 / 256f64
+  }
+
+  /// Get the number of bytes analyzed when the last of the 256 byte values first appeared,
+  /// or `None` while some byte values have not appeared yet.
+  /// Random data needs about 1568 bytes on average to show every byte value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// stats.analyze_slice( &[0u8;1000] );
+  /// stats.analyze_slice( &(0..255u8).collect::<Vec<u8>>() );
+  /// assert_eq!(stats.get_bytes_until_full(), None);
+  /// 
+  /// stats.analyze_slice( &[0, 1, 255, 255] );
+  /// assert_eq!(stats.get_bytes_until_full(), Some(1258));
+  /// ```
+  pub fn get_bytes_until_full(&self) -> Option<u128> {
+    if self.distinct < 256 {
+      return None
+    }
+    self.first.iter().copied().max()
+  }
+
+  /// Check the internal consistency of the analysis, to catch a broken analysis while debugging.
+  /// The counts of the distribution sum to the number of bytes analyzed, the first and last positions
+  /// of each byte value are within the set, and every byte after the first occurrence of its value
+  /// recorded one interval, every byte after the first one recorded one byte pair.
+  /// A saturated interval or byte pair count is only checked not to exceed the expected total.
+  /// Returns `false` on the first inconsistency found.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let mut stats = Bytestat::new();
+  /// let mut other = Bytestat::new();
+  /// stats.analyze_slice( b"The quick brown fox" );
+  /// other.analyze_slice( b" jumps over the lazy dog" );
+  /// stats.merge( &other );
+  /// 
+  /// assert!(Bytestat::new().validate());
+  /// assert!(stats.validate());
+  /// ```
+  ///
+  /// Each inconsistency fails the check, here set by the test only setters of the `test-setters` feature,
+  /// which the crate enables for its own tests.
+  ///
+  /// ```
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( b"hello" );
+  ///
+  /// let mut altered = stats.clone();
+  /// altered.set_dist_for_test( b'h', 2 );
+  /// assert!(!altered.validate());
+  ///
+  /// let mut altered = stats.clone();
+  /// altered.set_dist_for_test( b'h', u128::MAX );
+  /// assert!(!altered.validate());
+  ///
+  /// let mut altered = stats.clone();
+  /// altered.set_last_for_test( b'o', 6 );
+  /// assert!(!altered.validate());
+  ///
+  /// let mut altered = stats.clone();
+  /// altered.set_interval_for_test( 1, 2 );
+  /// assert!(!altered.validate());
+  ///
+  /// let mut restored = altered.clone();
+  /// restored.set_interval_for_test( 1, 1 );
+  /// assert!(restored.validate());
+  /// ```
+  ///
+  /// With the `serde` feature, an altered analysis can be loaded, it fails the check.
+  ///
+  /// ```
+  /// # #[cfg(feature = "serde")] {
+  /// use libbytestat::Bytestat;
+  /// let stats = Bytestat::from_slice( b"hello" );
+  /// let saved = serde_json::to_string(&stats).unwrap();
+  /// let altered:Bytestat = serde_json::from_str(&saved.replacen("\"counter\":5", "\"counter\":6", 1)).unwrap();
+  /// 
+  /// assert!(!altered.validate());
+  /// # }
+  /// ```
+  pub fn validate(&self) -> bool {
+    let mut total:u128 = 0;
+    let mut distinct = 0;
+    let mut squares:u128 = 0;
+    for x in 0..256 {
+      let count = self.dist[x];
+      let (first, last) = (self.first[x], self.last[x]);
+      if (count == 0) != (last == 0) || last > self.counter || first > last || (count > 0 && first == 0) {
+        return false
+      }
+      if count > 0 {
+        distinct += 1;
+      }
+      // An altered count can overflow the totals, it can not come from an analysis.
+      match (total.checked_add(count), count.checked_mul(count).and_then(|square| squares.checked_add(square))) {
+        (Some(new_total), Some(new_squares)) => {
+          total = new_total;
+          squares = new_squares;
+        }
+        _ => return false
+      }
+    }
+    if total != self.counter || distinct != self.distinct || squares != self.dist_squares {
+      return false
+    }
+    let expected = [self.counter - distinct as u128, self.counter.saturating_sub(1)];
+    for (table, expected) in [&self.interval, &self.digraph].iter().zip(expected) {
+      let saturated = table.contains(&u32::MAX);
+      let sum:u128 = table.iter().map(|x| *x as u128).sum();
+      if sum > expected || (!saturated && sum != expected) {
+        return false
+      }
+    }
+    true
+  }
+
+  /// Set the count of `value` without updating anything else, to test `validate`.
+  #[cfg(feature = "test-setters")]
+  #[doc(hidden)]
+  pub fn set_dist_for_test(&mut self, value:u8, count:u128) {
+    self.dist[value as usize] = count;
+  }
+
+  /// Set the last position of `value` without updating anything else, to test `validate`.
+  #[cfg(feature = "test-setters")]
+  #[doc(hidden)]
+  pub fn set_last_for_test(&mut self, value:u8, position:u128) {
+    self.last[value as usize] = position;
+  }
+
+  /// Set the count of the interval `gap` without updating anything else, to test `validate`.
+  #[cfg(feature = "test-setters")]
+  #[doc(hidden)]
+  pub fn set_interval_for_test(&mut self, gap:u16, count:u32) {
+    self.interval[gap as usize] = count;
+  }
+}
+
+/// Bytes written to a Bytestat are analyzed in order, as with `analyze_slice`.
+/// Writing never fails and flushing does nothing.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut copied = Bytestat::new();
+/// let mut analyzed = Bytestat::new();
+/// let data:Vec<u8> = (0..20000u32).map(|x| (x * 7 % 251) as u8).collect();
+/// 
+/// std::io::copy(&mut data.as_slice(), &mut copied).unwrap();
+/// analyzed.analyze_slice( &data );
+/// 
+/// assert_eq!(copied.get_scores_array(), analyzed.get_scores_array());
+/// ```
+#[cfg(feature = "std")]
+impl std::io::Write for Bytestat {
+  fn write(&mut self, buf:&[u8]) -> std::io::Result<usize> {
+    self.analyze_slice(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
+
+// Version of the layout written by `Bytestat::to_bytes`, to be increased whenever the layout changes.
+#[cfg(feature = "alloc")]
+const SNAPSHOT_VERSION:u8 = 1;
+
+#[cfg(feature = "alloc")]
+fn new_count_table() -> CountTable {
+  vec![0;256*256].into_boxed_slice()
+}
+
+#[cfg(not(feature = "alloc"))]
+fn new_count_table() -> CountTable {
+  [0;256*256]
+}
+
+#[cfg(feature = "alloc")]
+fn new_byte_counts() -> ByteCounts {
+  vec![0;256].into_boxed_slice()
+}
+
+#[cfg(not(feature = "alloc"))]
+fn new_byte_counts() -> ByteCounts {
+  [0;256]
+}
+
+#[cfg(feature = "std")]
+fn log2(x:f64) -> f64 {
+  x.log2()
+}
+
+#[cfg(not(feature = "std"))]
+fn log2(x:f64) -> f64 {
+  libm::log2(x)
+}
+
+#[cfg(feature = "std")]
+fn sqrt(x:f64) -> f64 {
+  x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+fn sqrt(x:f64) -> f64 {
+  libm::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+fn ln(x:f64) -> f64 {
+  x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+fn ln(x:f64) -> f64 {
+  libm::log(x)
+}
+
+#[cfg(feature = "std")]
+fn exp(x:f64) -> f64 {
+  x.exp()
+}
+
+#[cfg(not(feature = "std"))]
+fn exp(x:f64) -> f64 {
+  libm::exp(x)
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+fn sin_cos(x:f64) -> (f64, f64) {
+  x.sin_cos()
+}
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+fn sin_cos(x:f64) -> (f64, f64) {
+  libm::sincos(x)
+}
+
+// Natural logarithm of the gamma function, for x > 0, with the Lanczos approximation (g = 7, 9 coefficients).
+fn ln_gamma(x:f64) -> f64 {
+  const COEFFICIENTS:[f64;9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+  ];
+  let x = x - 1.0;
+  let mut sum = COEFFICIENTS[0];
+  for (i, coefficient) in COEFFICIENTS.iter().enumerate().skip(1) {
+    sum += coefficient / (x + i as f64);
+  }
+  let t = x + 7.5;
+  0.5 * ln(2.0 * core::f64::consts::PI) + (x + 0.5) * ln(t) - t + ln(sum)
+}
+
+// Cumulative distribution function of the standard normal distribution, erfc(-x / √2) / 2 with erfc(y) = Q(1 / 2, y²).
+fn normal_cdf(x:f64) -> f64 {
+  let tail = gamma_upper_regularized(0.5, x * x / 2.0) / 2.0;
+  if x < 0.0 { tail } else { 1.0 - tail }
+}
+
+// Upper regularized incomplete gamma function Q(a, x), for a > 0.
+// The series converges quickly below a + 1, the continued fraction (modified Lentz) above.
+fn gamma_upper_regularized(a:f64, x:f64) -> f64 {
+  if x <= 0.0 {
+    return 1.0
+  }
+  let prefix = exp(a * ln(x) - x - ln_gamma(a));
+  if x < a + 1.0 {
+    let mut term = 1.0 / a;
+    let mut sum = term;
+    let mut n = a;
+    for _ in 0..1000 {
+      n += 1.0;
+      term *= x / n;
+      sum += term;
+      if term.abs() < sum.abs() * 1e-15 {
+        break
+      }
+    }
+    (1.0 - sum * prefix).clamp(0.0, 1.0)
+  }
+  else {
+    let tiny = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut fraction = d;
+    for i in 1..1000 {
+      let an = -(i as f64) * (i as f64 - a);
+      b += 2.0;
+      d = an * d + b;
+      if d.abs() < tiny {
+        d = tiny;
+      }
+      c = b + an / c;
+      if c.abs() < tiny {
+        c = tiny;
+      }
+      d = 1.0 / d;
+      let delta = d * c;
+      fraction *= delta;
+      if (delta - 1.0).abs() < 1e-15 {
+        break
+      }
+    }
+    (fraction * prefix).clamp(0.0, 1.0)
+  }
+}
+
+// Decode standard base64, calling `f` with each byte. Whitespace is skipped, the padding is optional.
+#[cfg(feature = "base64")]
+fn decode_base64<F: FnMut(u8)>(base64:&str, mut f:F) -> Result<(), Base64Error> {
+  let mut bits:u32 = 0;
+  let mut bits_len = 0;
+  let mut digits = 0;
+  let mut padding = 0;
+  for c in base64.chars() {
+    if c.is_whitespace() {
+      continue
+    }
+    if c == '=' {
+      padding += 1;
+      continue
+    }
+    let digit = match c {
+      'A'..='Z' => c as u32 - 'A' as u32,
+      'a'..='z' => c as u32 - 'a' as u32 + 26,
+      '0'..='9' => c as u32 - '0' as u32 + 52,
+      '+' => 62,
+      '/' => 63,
+      _ => return Err(Base64Error::InvalidCharacter(c))
+    };
+    if padding > 0 {
+      return Err(Base64Error::InvalidCharacter(c))
+    }
+    digits += 1;
+    bits = bits << 6 | digit;
+    bits_len += 6;
+    if bits_len >= 8 {
+      bits_len -= 8;
+      f((bits >> bits_len) as u8);
+      bits &= (1 << bits_len) - 1;
+    }
+  }
+  if digits % 4 == 1 || (padding > 0 && (digits + padding) % 4 != 0) || padding > 2 {
+    return Err(Base64Error::InvalidLength)
+  }
+  Ok(())
+}
+
+// Call `f` with each byte decoded from `hex`, stop at the first error.
+fn decode_hex<F: FnMut(u8)>(hex:&str, mut f:F) -> Result<(), HexError> {
+  let mut high:Option<u8> = None;
+  for c in hex.chars() {
+    if high.is_none() && c.is_whitespace() {
+      continue
+    }
+    let digit = match c.to_digit(16) {
+      Some(digit) => digit as u8,
+      None => return Err(HexError::InvalidDigit(c))
+    };
+    match high {
+      Some(value) => {
+        f(value << 4 | digit);
+        high = None;
+      },
+      None => high = Some(digit)
+    }
+  }
+  if high.is_some() {
+    return Err(HexError::OddLength)
+  }
+  Ok(())
+}
+
+// Φ(m) of the approximate entropy, the mean logarithm of the proportion of windows matching each window of `m` bytes.
+#[cfg(feature = "alloc")]
+fn approx_entropy_phi(sample:&[u8], m:usize, r:u8) -> f64 {
+  let count = sample.len() - m + 1;
+  let mut total = 0f64;
+  for i in 0..count {
+    let window = &sample[i..i + m];
+    let matches = (0..count)
+      .filter(|j| window.iter().zip(&sample[*j..*j + m]).all(|(a, b)| a.abs_diff(*b) <= r))
+      .count();
+    total += log2(matches as f64 / count as f64);
+  }
+  total * core::f64::consts::LN_2 / count as f64
+}
+
+fn interval_slot(gap:u128) -> usize {
+  if gap > u16::MAX as u128 { u16::MAX as usize } else { gap as usize }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_byte_counts<'de, D: serde::Deserializer<'de>>(deserializer:D) -> Result<Box<[u128]>, D::Error> {
+  let counts = <Box<[u128]> as serde::Deserialize>::deserialize(deserializer)?;
+  if counts.len() != 256 {
+    return Err(serde::de::Error::invalid_length(counts.len(), &"256 counts"))
+  }
+  Ok(counts)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_probabilities<'de, D: serde::Deserializer<'de>>(deserializer:D) -> Result<Option<Box<[f64]>>, D::Error> {
+  let probabilities = <Option<Box<[f64]>> as serde::Deserialize>::deserialize(deserializer)?;
+  match &probabilities {
+    Some(values) if values.len() != 256 => Err(serde::de::Error::invalid_length(values.len(), &"256 probabilities")),
+    _ => Ok(probabilities)
+  }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_count_table<'de, D: serde::Deserializer<'de>>(deserializer:D) -> Result<Box<[u32]>, D::Error> {
+  let interval = <Box<[u32]> as serde::Deserialize>::deserialize(deserializer)?;
+  if interval.len() != 256*256 {
+    return Err(serde::de::Error::invalid_length(interval.len(), &"65536 counts"))
+  }
+  Ok(interval)
+}
+
+/// Same as `Bytestat::new()`.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut from_default = Bytestat::default();
+/// let mut from_new = Bytestat::new();
+/// from_default.analyze_slice( b"same input" );
+/// from_new.analyze_slice( b"same input" );
+/// 
+/// assert_eq!(from_default.get_scores_array(), from_new.get_scores_array());
+/// ```
+impl Default for Bytestat {
+  fn default() -> Bytestat {
+    Bytestat::new()
+  }
+}
+
+/// Compare the accumulators and the configuration of two analyses.
+/// The scores are derived from them, two equal analyses have equal scores and keep being equal when fed the same bytes.
+/// The history of captured scores, the scores kept by `get_score_delta` and the early part of `get_stationarity_score`,
+/// which depends on how the set was merged, are not compared.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut original = Bytestat::new();
+/// original.analyze_slice( b"The quick brown fox jumps over the lazy dog" );
+/// let mut fork = original.clone();
+/// 
+/// assert_eq!(fork, original);
+/// fork.analyze( 0 );
+/// assert_ne!(fork, original);
+/// original.analyze( 0 );
+/// assert_eq!(fork, original);
+/// original.set_significance_divisor( 256 );
+/// assert_ne!(fork, original);
+/// ```
+impl PartialEq for Bytestat {
+  fn eq(&self, other:&Bytestat) -> bool {
+    #[cfg(feature = "alloc")]
+    if self.lags != other.lags || self.maurer != other.maurer || self.sample != other.sample || self.sample_size != other.sample_size
+      || self.gaps != other.gaps || self.history_interval != other.history_interval {
+      return false
+    }
+    self.counter == other.counter
+      && self.dist == other.dist
+      && self.interval == other.interval
+      && self.digraph == other.digraph
+      && self.last == other.last
+      && self.first == other.first
+      && self.previous == other.previous
+      && self.sum == other.sum
+      && self.sum_squares == other.sum_squares
+      && self.sum_products == other.sum_products
+      && self.point == other.point
+      && self.point_len == other.point_len
+      && self.points == other.points
+      && self.points_inside == other.points_inside
+      && self.above == other.above
+      && self.runs == other.runs
+      && self.ones == other.ones
+      && self.nibbles == other.nibbles
+      && self.walk_max == other.walk_max
+      && self.walk_min == other.walk_min
+      && self.run_length == other.run_length
+      && self.leading_run == other.leading_run
+      && self.longest_run == other.longest_run
+      && self.expected == other.expected
+      && self.max_interval == other.max_interval
+      && self.interval_amplitude_divisor == other.interval_amplitude_divisor
+      && self.significance_divisor == other.significance_divisor
+      && self.weights == other.weights
+      && self.enabled == other.enabled
+  }
+}
+
+/// Create new Bytestat object and analyze the bytes decoded from a hexadecimal string, see `analyze_hex`.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::{Bytestat, BytestatError};
+/// use std::convert::TryFrom;
+/// let stats = Bytestat::try_from( "68 65 6c 6c 6f" ).unwrap();
+/// 
+/// assert_eq!(stats, Bytestat::from_slice( b"hello" ));
+/// assert!(matches!(Bytestat::try_from( "6g" ), Err(BytestatError::InvalidHex(_))));
+/// ```
+impl core::convert::TryFrom<&str> for Bytestat {
+  type Error = BytestatError;
+
+  fn try_from(hex:&str) -> Result<Bytestat, BytestatError> {
+    let mut stats = Bytestat::new();
+    stats.analyze_hex(hex)?;
+    Ok(stats)
+  }
+}
+
+/// Collect bytes into a new Bytestat, analyzing them in the order they are produced.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut collected:Bytestat = (0u8..=255).cycle().take(4096).collect();
+/// let mut looped = Bytestat::new();
+/// for x in (0u8..=255).cycle().take(4096) {
+///   looped.analyze( x );
+/// }
+/// 
+/// assert_eq!(collected.get_scores_array(), looped.get_scores_array());
+/// ```
+impl core::iter::FromIterator<u8> for Bytestat {
+  fn from_iter<I: IntoIterator<Item = u8>>(iter:I) -> Bytestat {
+    let mut stats = Bytestat::new();
+    for value in iter {
+      stats.analyze(value);
+    }
+    stats
+  }
+}
+
+/// Analyze the bytes of an iterator, in order, after the ones already analyzed.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let data:Vec<u8> = (0..10000u32).map(|x| (x * 13 % 256) as u8).collect();
+/// let mut extended = Bytestat::new();
+/// let mut looped = Bytestat::new();
+/// 
+/// extended.extend( data[..3000].iter().copied() );
+/// extended.analyze( data[3000] );
+/// extended.extend( &data[3001..] );
+/// for x in &data {
+///   looped.analyze( *x );
+/// }
+/// 
+/// assert_eq!(extended.get_scores_array(), looped.get_scores_array());
+/// ```
+impl Extend<u8> for Bytestat {
+  fn extend<I: IntoIterator<Item = u8>>(&mut self, iter:I) {
+    for value in iter {
+      self.analyze(value);
+    }
+  }
+}
+
+impl<'a> Extend<&'a u8> for Bytestat {
+  fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter:I) {
+    for value in iter {
+      self.analyze(*value);
+    }
+  }
+}
+
+/// Iterate over the distribution, the count of each of the 256 byte values, in byte order.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let stats = Bytestat::from_slice( b"hello" );
+/// let present:Vec<(u8, u128)> = (&stats).into_iter().filter(|(_, count)| *count > 0).collect();
+/// 
+/// assert_eq!(present, [(b'e', 1), (b'h', 1), (b'l', 2), (b'o', 1)]);
+/// for (value, count) in &stats {
+///   assert_eq!(count, stats.get_distribution()[value as usize]);
+/// }
+/// ```
+impl<'a> IntoIterator for &'a Bytestat {
+  type Item = (u8, u128);
+  type IntoIter = core::iter::Map<core::iter::Enumerate<core::slice::Iter<'a, u128>>, fn((usize, &u128)) -> (u8, u128)>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.dist.iter().enumerate().map(|(value, count)| (value as u8, *count))
+  }
+}
+
+/// Show the byte counter and the scores.
+/// The distribution and interval tables are left out.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut stats = Bytestat::new();
+/// stats.analyze_slice( &[0u8;16] );
+/// 
+/// assert_eq!(format!("{:?}", stats), "Bytestat { counter: 16, score_non_zero: 0.00390625, score_unique: 0.00390625, \
+/// score_amplitude: 0.0, score_interval_continuity: 0.001953125, score_interval_amplitude: 0.001953125, score: 0.234375 }");
+/// ```
+impl core::fmt::Debug for Bytestat {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let scores = self.compute_scores();
+    f.debug_struct("Bytestat")
+      .field("counter", &self.counter)
+      .field("score_non_zero", &scores.non_zero)
+      .field("score_unique", &scores.unique)
+      .field("score_amplitude", &scores.amplitude)
+      .field("score_interval_continuity", &scores.interval_continuity)
+      .field("score_interval_amplitude", &scores.interval_amplitude)
+      .field("score", &scores.total)
+      .finish()
+  }
+}
+
+/// Show a report of the analysis: the byte counter, each score as a percentage and the final score.
+/// The final score is rounded up like `get_score_rounded`, with a `~` prefix when the set is not significant yet,
+/// see `is_significant`.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::Bytestat;
+/// let mut stats = Bytestat::new();
+/// stats.analyze_slice( &(0..=255u8).collect::<Vec<u8>>() );
+/// let report = stats.to_string();
+///
+/// assert!(report.starts_with("256 samples\n"));
+/// assert!(report.contains("non zero: 100.00%\n"));
+/// assert!(report.contains("interval amplitude: 0.00%\n"));
+/// assert!(report.ends_with("score: ~40%\n"));
+///
+/// let mut lenient = Bytestat::new();
+/// lenient.set_significance_divisor( 1 );
+/// lenient.analyze_slice( &(0..=255u8).cycle().take(25600).collect::<Vec<u8>>() );
+/// assert!(lenient.is_significant());
+/// assert!(lenient.to_string().ends_with("score: 40%\n"));
+///
+/// // Only the non zero score is counted, 254 bytes present score 99.22.
+/// let mut fractional = Bytestat::builder()
+///   .unique( false )
+///   .amplitude( false )
+///   .interval_continuity( false )
+///   .interval_amplitude( false )
+///   .build();
+/// fractional.analyze_slice( &(0..254u8).collect::<Vec<u8>>() );
+/// assert!(fractional.get_score() < 99.5);
+/// assert!(fractional.to_string().ends_with("score: ~100%\n"));
+/// ```
+impl core::fmt::Display for Bytestat {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let scores = self.compute_scores();
+    writeln!(f, "{} samples", self.counter)?;
+    writeln!(f, "non zero: {:.2}%", scores.non_zero * 100.0)?;
+    writeln!(f, "unique: {:.2}%", scores.unique * 100.0)?;
+    writeln!(f, "amplitude: {:.2}%", scores.amplitude * 100.0)?;
+    writeln!(f, "interval continuity: {:.2}%", scores.interval_continuity * 100.0)?;
+    writeln!(f, "interval amplitude: {:.2}%", scores.interval_amplitude * 100.0)?;
+    writeln!(f, "score: {}{}%", if self.is_significant() { "" } else { "~" }, self.get_score_rounded())
   }
 }
@@ -0,0 +1,112 @@
+//! # Maurer's universal test
+//!
+//! Distances between the repetitions of blocks of bits, used by Maurer's universal statistical test.
+
+use alloc::{vec, vec::Vec};
+use crate::SnapshotError;
+use crate::snapshot::{SnapshotReader, SnapshotWriter};
+
+// The bits are split in blocks of `block_bits` bits, most significant bit first.
+// The first 10 * 2^block_bits blocks only initialize the last position of each block,
+// the log2 of the distance to the previous occurrence of each following block is summed.
+#[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct MaurerTracker {
+    block_bits:u8,
+    last:Vec<u64>,
+    bits:u32,
+    bits_len:u8,
+    blocks:u64,
+    sum:f64,
+}
+
+impl MaurerTracker {
+  pub(crate) fn new(block_bits:u8) -> MaurerTracker {
+    MaurerTracker {
+      block_bits,
+      last:vec![0;if block_bits == 0 { 0 } else { 1 << block_bits }],
+      ..MaurerTracker::default()
+    }
+  }
+
+  pub(crate) fn reset(&mut self) {
+    self.last.fill(0);
+    self.bits = 0;
+    self.bits_len = 0;
+    self.blocks = 0;
+    self.sum = 0.0;
+  }
+
+  pub(crate) fn push(&mut self, value:u8) {
+    if self.block_bits == 0 {
+      return
+    }
+    self.bits = (self.bits << 8) | value as u32;
+    self.bits_len += 8;
+    while self.bits_len >= self.block_bits {
+      self.bits_len -= self.block_bits;
+      let block = ((self.bits >> self.bits_len) & ((1 << self.block_bits) - 1)) as usize;
+      self.blocks += 1;
+      if self.blocks > self.initialization_blocks() {
+        self.sum += crate::log2((self.blocks - self.last[block]) as f64);
+      }
+      self.last[block] = self.blocks;
+    }
+    self.bits &= (1 << self.bits_len) - 1;
+  }
+
+  // Push `value` `count` times, one at a time: the sum must be the same as with `push`, to the last bit.
+  pub(crate) fn push_repeated(&mut self, value:u8, count:u128) {
+    if self.block_bits == 0 {
+      return
+    }
+    for _ in 0..count {
+      self.push(value);
+    }
+  }
+
+  pub(crate) fn initialization_blocks(&self) -> u64 {
+    10 << self.block_bits
+  }
+
+  pub(crate) fn test_blocks(&self) -> u64 {
+    self.blocks.saturating_sub(self.initialization_blocks())
+  }
+
+  pub(crate) fn sum(&self) -> f64 {
+    self.sum
+  }
+
+  // The block size, the pending bits, the blocks count, the sum and the last position of each block.
+  pub(crate) fn write_snapshot(&self, snapshot:&mut SnapshotWriter) {
+    snapshot.u8(self.block_bits);
+    snapshot.u32(self.bits);
+    snapshot.u8(self.bits_len);
+    snapshot.u64(self.blocks);
+    snapshot.f64(self.sum);
+    for position in &self.last {
+      snapshot.u64(*position);
+    }
+  }
+
+  pub(crate) fn read_snapshot(snapshot:&mut SnapshotReader<'_>) -> Result<MaurerTracker, SnapshotError> {
+    let block_bits = snapshot.u8()?;
+    let bits = snapshot.u32()?;
+    let bits_len = snapshot.u8()?;
+    if block_bits > 16 || (bits_len > 0 && bits_len >= block_bits) || bits >> bits_len != 0 {
+      return Err(SnapshotError::Corrupted)
+    }
+    let mut stats = MaurerTracker::new(block_bits);
+    stats.bits = bits;
+    stats.bits_len = bits_len;
+    stats.blocks = snapshot.u64()?;
+    stats.sum = snapshot.f64()?;
+    for position in stats.last.iter_mut() {
+      *position = snapshot.u64()?;
+      if *position > stats.blocks {
+        return Err(SnapshotError::Corrupted)
+      }
+    }
+    Ok(stats)
+  }
+}
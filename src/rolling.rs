@@ -0,0 +1,203 @@
+//! # Rolling analysis
+//!
+//! Analysis restricted to the most recent bytes of a stream.
+
+use alloc::{vec, vec::Vec};
+use crate::{Bytestat, Scores};
+
+/// Analysis of the last `size` bytes of a stream.
+/// Bytes older than the window are removed from the distribution and the intervals as new bytes arrive.
+///
+/// The window keeps each byte along with the interval to the next occurrence of the same value, 0 if there is none yet.
+/// When a byte leaves the window, its count is removed from the distribution and the interval to its next occurrence,
+/// if any, is removed from the interval table.
+/// That next occurrence becomes the first occurrence of its value in the window, with no interval, as in `Bytestat`.
+/// The scores of the window are always the same as the scores of a new `Bytestat` fed only with the bytes of the window.
+///
+/// The window uses 16 bytes of memory per byte of `size`.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::{Bytestat, RollingBytestat};
+/// let mut rolling = RollingBytestat::new( 4096 );
+/// # let mut seed:u32 = 1;
+/// # let mut get_random_byte = || { seed = seed.wrapping_mul(1103515245).wrapping_add(12345); (seed >> 16) as u8 };
+/// let random:Vec<u8> = (0..100000).map(|_| get_random_byte()).collect();
+///
+/// rolling.analyze_slice( &random );
+/// let mut window = Bytestat::new();
+/// window.analyze_slice( &random[100000 - 4096..] );
+/// assert_eq!(rolling.get_scores(), window.get_scores());
+/// let random_score = rolling.get_score();
+///
+/// rolling.analyze_slice( &[0u8;4096] );
+/// let mut constant = Bytestat::new();
+/// constant.analyze_slice( &[0u8;4096] );
+/// assert_eq!(rolling.get_scores(), constant.get_scores());
+/// assert!(rolling.get_score() < random_score);
+/// ```
+#[derive(Clone)]
+pub struct RollingBytestat {
+    stats:Bytestat,
+    window:Vec<(u8, u64)>,
+    size:usize,
+}
+
+impl RollingBytestat {
+  /// Create new RollingBytestat object, analyzing the last `size` bytes.
+  /// A size of 0 is treated as 1.
+  ///
+  /// # Arguments
+  ///
+  /// * `size` - The number of bytes in the window, usize
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::RollingBytestat;
+  /// let stats = RollingBytestat::new( 65536 );
+  /// ```
+  pub fn new(size:usize) -> RollingBytestat {
+    let size = if size == 0 { 1 } else { size };
+    RollingBytestat {
+      stats:Bytestat::new(),
+      window:vec![(0, 0);size],
+      size,
+    }
+  }
+
+  /// Analyze one byte, removing the oldest byte of the window once it is full.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - A byte to be analyzed, u8
+  pub fn analyze(&mut self, value:u8) {
+    let position = self.stats.counter + 1;
+
+    if position > self.size as u128 {
+      let oldest = position - self.size as u128;
+      let (old_value, next_gap) = self.window[self.slot(oldest)];
+      self.stats.forget(old_value, next_gap as u128);
+      if next_gap == 0 {
+        self.stats.last[old_value as usize] = 0;
+      }
+    }
+
+    let last = self.stats.last[value as usize];
+    if last > 0 {
+      let slot = self.slot(last);
+      self.window[slot].1 = (position - last) as u64;
+    }
+    let slot = self.slot(position);
+    self.window[slot] = (value, 0);
+    self.stats.analyze(value);
+  }
+
+  /// Analyze a slice of bytes, in order.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  pub fn analyze_slice(&mut self, data:&[u8]) {
+    for value in data {
+      self.analyze(*value);
+    }
+  }
+
+  /// Get the number of bytes in the window.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::RollingBytestat;
+  /// let mut stats = RollingBytestat::new( 100 );
+  /// stats.analyze_slice( &[0u8;60] );
+  /// assert_eq!(stats.get_counter(), 60);
+  /// stats.analyze_slice( &[0u8;60] );
+  /// assert_eq!(stats.get_counter(), 100);
+  /// ```
+  pub fn get_counter(&self) -> u128 {
+    if self.stats.counter < self.size as u128 { self.stats.counter } else { self.size as u128 }
+  }
+
+  /// Get the size of the window, the maximum number of bytes analyzed at once.
+  pub fn get_size(&self) -> usize {
+    self.size
+  }
+
+  /// Generate the score based on distribution of unique bytes being present in the window.
+  /// See `Bytestat::get_score_non_zero`.
+  pub fn get_score_non_zero(&self) -> f64 {
+    self.get_scores().non_zero
+  }
+
+  /// Generate the score based on the uniqueness of the bytes distribution in the window.
+  /// See `Bytestat::get_score_unique`.
+  pub fn get_score_unique(&self) -> f64 {
+    self.get_scores().unique
+  }
+
+  /// Generate the score based on the amplitude of the bytes distribution in the window.
+  /// See `Bytestat::get_score_amplitude`.
+  pub fn get_score_amplitude(&self) -> f64 {
+    self.get_scores().amplitude
+  }
+
+  /// Generate the score based on the continuity of significant intervals in the window.
+  /// See `Bytestat::get_score_interval_continuity`.
+  pub fn get_score_interval_continuity(&self) -> f64 {
+    self.get_scores().interval_continuity
+  }
+
+  /// Generate the score based on the amplitude of significant intervals in the window.
+  /// See `Bytestat::get_score_interval_amplitude`.
+  pub fn get_score_interval_amplitude(&self) -> f64 {
+    self.get_scores().interval_amplitude
+  }
+
+  /// Generate the final score of the window, between 0 and 100.
+  /// See `Bytestat::get_score`.
+  pub fn get_score(&self) -> f64 {
+    self.get_scores().total
+  }
+
+  /// Generate all the scores of the window at once, as named fields.
+  pub fn get_scores(&self) -> Scores {
+    self.stats.compute_scores_for(self.get_counter())
+  }
+
+  /// Generate all the scores of the window at once, in the order of the fields of `Scores`.
+  pub fn get_scores_array(&self) -> [f64;6] {
+    let scores = self.get_scores();
+    [
+      scores.non_zero,
+      scores.unique,
+      scores.amplitude,
+      scores.interval_continuity,
+      scores.interval_amplitude,
+      scores.total
+    ]
+  }
+
+  fn slot(&self, position:u128) -> usize {
+    ((position - 1) % self.size as u128) as usize
+  }
+}
+
+/// Show the window size, the number of bytes in the window and the scores of the window.
+impl core::fmt::Debug for RollingBytestat {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let scores = self.get_scores();
+    f.debug_struct("RollingBytestat")
+      .field("size", &self.size)
+      .field("counter", &self.get_counter())
+      .field("score_non_zero", &scores.non_zero)
+      .field("score_unique", &scores.unique)
+      .field("score_amplitude", &scores.amplitude)
+      .field("score_interval_continuity", &scores.interval_continuity)
+      .field("score_interval_amplitude", &scores.interval_amplitude)
+      .field("score", &scores.total)
+      .finish()
+  }
+}
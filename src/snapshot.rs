@@ -0,0 +1,177 @@
+//! # Binary snapshot
+//!
+//! Little-endian encoding of the state of an analysis, used by `Bytestat::to_bytes` and `Bytestat::from_bytes`.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use crate::{Scores, SnapshotError, Weights};
+
+// Appends the values to the snapshot, in little-endian order.
+pub(crate) struct SnapshotWriter {
+    data:Vec<u8>,
+}
+
+impl SnapshotWriter {
+  pub(crate) fn new(capacity:usize) -> SnapshotWriter {
+    SnapshotWriter {
+      data:Vec::with_capacity(capacity),
+    }
+  }
+
+  pub(crate) fn into_inner(self) -> Vec<u8> {
+    self.data
+  }
+
+  pub(crate) fn bytes(&mut self, values:&[u8]) {
+    self.data.extend_from_slice(values);
+  }
+
+  pub(crate) fn u8(&mut self, value:u8) {
+    self.data.push(value);
+  }
+
+  pub(crate) fn u16(&mut self, value:u16) {
+    self.bytes(&value.to_le_bytes());
+  }
+
+  pub(crate) fn u32(&mut self, value:u32) {
+    self.bytes(&value.to_le_bytes());
+  }
+
+  pub(crate) fn u64(&mut self, value:u64) {
+    self.bytes(&value.to_le_bytes());
+  }
+
+  pub(crate) fn u128(&mut self, value:u128) {
+    self.bytes(&value.to_le_bytes());
+  }
+
+  pub(crate) fn i128(&mut self, value:i128) {
+    self.bytes(&value.to_le_bytes());
+  }
+
+  pub(crate) fn f64(&mut self, value:f64) {
+    self.bytes(&value.to_le_bytes());
+  }
+
+  // A length or a size, always written on 8 bytes.
+  pub(crate) fn len(&mut self, value:usize) {
+    self.u64(value as u64);
+  }
+
+  pub(crate) fn scores(&mut self, scores:&Scores) {
+    for x in [scores.non_zero, scores.unique, scores.amplitude, scores.interval_continuity, scores.interval_amplitude, scores.total] {
+      self.f64(x);
+    }
+  }
+
+  pub(crate) fn weights(&mut self, weights:&Weights) {
+    for x in [weights.non_zero, weights.unique, weights.amplitude, weights.interval_continuity, weights.interval_amplitude] {
+      self.f64(x);
+    }
+  }
+}
+
+// Reads the values of a snapshot in the order they were written, any read past the end is `Truncated`.
+pub(crate) struct SnapshotReader<'a> {
+    data:&'a [u8],
+}
+
+impl<'a> SnapshotReader<'a> {
+  pub(crate) fn new(data:&'a [u8]) -> SnapshotReader<'a> {
+    SnapshotReader {
+      data,
+    }
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+
+  pub(crate) fn bytes(&mut self, len:usize) -> Result<&'a [u8], SnapshotError> {
+    if len > self.data.len() {
+      return Err(SnapshotError::Truncated)
+    }
+    let (values, rest) = self.data.split_at(len);
+    self.data = rest;
+    Ok(values)
+  }
+
+  fn array<const N:usize>(&mut self) -> Result<[u8;N], SnapshotError> {
+    let mut array = [0;N];
+    array.copy_from_slice(self.bytes(N)?);
+    Ok(array)
+  }
+
+  pub(crate) fn u8(&mut self) -> Result<u8, SnapshotError> {
+    Ok(self.bytes(1)?[0])
+  }
+
+  pub(crate) fn bool(&mut self) -> Result<bool, SnapshotError> {
+    match self.u8()? {
+      0 => Ok(false),
+      1 => Ok(true),
+      _ => Err(SnapshotError::Corrupted)
+    }
+  }
+
+  pub(crate) fn u16(&mut self) -> Result<u16, SnapshotError> {
+    Ok(u16::from_le_bytes(self.array()?))
+  }
+
+  pub(crate) fn u32(&mut self) -> Result<u32, SnapshotError> {
+    Ok(u32::from_le_bytes(self.array()?))
+  }
+
+  pub(crate) fn u64(&mut self) -> Result<u64, SnapshotError> {
+    Ok(u64::from_le_bytes(self.array()?))
+  }
+
+  pub(crate) fn u128(&mut self) -> Result<u128, SnapshotError> {
+    Ok(u128::from_le_bytes(self.array()?))
+  }
+
+  pub(crate) fn i128(&mut self) -> Result<i128, SnapshotError> {
+    Ok(i128::from_le_bytes(self.array()?))
+  }
+
+  pub(crate) fn f64(&mut self) -> Result<f64, SnapshotError> {
+    Ok(f64::from_le_bytes(self.array()?))
+  }
+
+  // The length of a list of `element_size` bytes elements, which must fit in the rest of the snapshot,
+  // a corrupted length can not cause a large allocation.
+  pub(crate) fn len(&mut self, element_size:usize) -> Result<usize, SnapshotError> {
+    let len = self.u64()?;
+    if len > (self.data.len() / element_size) as u64 {
+      return Err(SnapshotError::Truncated)
+    }
+    Ok(len as usize)
+  }
+
+  // A size that is not followed by as many elements, like the capacity of the sample.
+  pub(crate) fn size(&mut self) -> Result<usize, SnapshotError> {
+    usize::try_from(self.u64()?).map_err(|_| SnapshotError::Corrupted)
+  }
+
+  pub(crate) fn scores(&mut self) -> Result<Scores, SnapshotError> {
+    Ok(Scores {
+      non_zero:self.f64()?,
+      unique:self.f64()?,
+      amplitude:self.f64()?,
+      interval_continuity:self.f64()?,
+      interval_amplitude:self.f64()?,
+      total:self.f64()?,
+    })
+  }
+
+  pub(crate) fn weights(&mut self) -> Result<Weights, SnapshotError> {
+    Ok(Weights {
+      non_zero:self.f64()?,
+      unique:self.f64()?,
+      amplitude:self.f64()?,
+      interval_continuity:self.f64()?,
+      interval_amplitude:self.f64()?,
+    })
+  }
+}
@@ -0,0 +1,118 @@
+//! # Shared analysis
+//!
+//! Analysis shared between threads, behind a lock.
+
+use std::sync::{Mutex, MutexGuard};
+use crate::{Bytestat, Scores};
+
+/// Analysis of a sequence of bytes shared between threads, with the `std` feature.
+///
+/// Every method locks the analysis for its duration, the bytes of concurrent calls are analyzed
+/// one call after the other, in the order the lock is taken.
+/// Each call pays for the lock and the threads wait for each other: under contention, the throughput drops.
+/// For a hot path, analyze a part of the set in each thread with its own `Bytestat`, then join them with `merge`.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::SyncBytestat;
+/// use std::sync::Arc;
+/// let shared = Arc::new(SyncBytestat::new());
+/// let producers:Vec<_> = (0..4u8).map(|x| {
+///   let shared = Arc::clone(&shared);
+///   std::thread::spawn(move || {
+///     for y in 0..1000u32 {
+///       shared.analyze_slice( &[x, (y % 256) as u8] );
+///     }
+///   })
+/// }).collect();
+/// for producer in producers {
+///   producer.join().unwrap();
+/// }
+///
+/// assert_eq!(shared.get_counter(), 8000);
+/// assert_eq!(Arc::try_unwrap(shared).unwrap().into_inner().get_distribution()[0], 1000 + 4 * 4);
+/// ```
+#[derive(Default)]
+pub struct SyncBytestat {
+    stats:Mutex<Bytestat>,
+}
+
+impl SyncBytestat {
+  /// Create new SyncBytestat object, sharing a new `Bytestat`.
+  pub fn new() -> SyncBytestat {
+    SyncBytestat::from(Bytestat::new())
+  }
+
+  // A panic while the lock was held leaves the analysis usable, the lock is taken anyway.
+  fn lock(&self) -> MutexGuard<'_, Bytestat> {
+    self.stats.lock().unwrap_or_else(|err| err.into_inner())
+  }
+
+  /// Analyze one byte, see `Bytestat::analyze`.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - A byte to be analyzed, u8
+  pub fn analyze(&self, value:u8) {
+    self.lock().analyze(value);
+  }
+
+  /// Analyze a slice of bytes, in order, without the bytes of another thread in between.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The bytes to be analyzed, &[u8]
+  pub fn analyze_slice(&self, data:&[u8]) {
+    self.lock().analyze_slice(data);
+  }
+
+  /// Add an analysis of the bytes following the ones already analyzed, see `Bytestat::merge`.
+  ///
+  /// # Arguments
+  ///
+  /// * `other` - The analysis of the following bytes, &Bytestat
+  pub fn merge(&self, other:&Bytestat) {
+    self.lock().merge(other);
+  }
+
+  /// Get the number of bytes analyzed.
+  pub fn get_counter(&self) -> u128 {
+    self.lock().get_counter()
+  }
+
+  /// Generate the final score, see `Bytestat::get_score`.
+  pub fn get_score(&self) -> f64 {
+    self.lock().get_score()
+  }
+
+  /// Generate all the scores at once, see `Bytestat::get_scores`.
+  pub fn get_scores(&self) -> Scores {
+    self.lock().get_scores()
+  }
+
+  /// Get a copy of the analysis so far, to query any other metric.
+  pub fn snapshot(&self) -> Bytestat {
+    self.lock().clone()
+  }
+
+  /// Get the shared analysis back.
+  pub fn into_inner(self) -> Bytestat {
+    self.stats.into_inner().unwrap_or_else(|err| err.into_inner())
+  }
+}
+
+impl From<Bytestat> for SyncBytestat {
+  fn from(stats:Bytestat) -> SyncBytestat {
+    SyncBytestat {
+      stats:Mutex::new(stats),
+    }
+  }
+}
+
+/// Show the shared analysis.
+impl core::fmt::Debug for SyncBytestat {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_tuple("SyncBytestat").field(&*self.lock()).finish()
+  }
+}
@@ -0,0 +1,67 @@
+//! # WebAssembly
+//!
+//! JavaScript bindings of the analysis, with the `wasm` feature.
+
+use wasm_bindgen::prelude::*;
+use crate::Bytestat;
+
+/// Analysis of a sequence of bytes for JavaScript, exported as `Bytestat`, with the `wasm` feature.
+///
+/// The large tables of the analysis are on the heap, it fits the small default stack of WebAssembly.
+/// The methods are exported in camel case: `analyzeBytes`, `getScore`, `getCounter` and `isSignificant`.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::WasmBytestat;
+/// let mut stats = WasmBytestat::new();
+/// stats.analyze_bytes( b"The quick brown fox jumps over the lazy dog" );
+///
+/// assert_eq!(stats.get_counter(), 43.0);
+/// assert_eq!(stats.get_score(), libbytestat::Bytestat::from_slice( b"The quick brown fox jumps over the lazy dog" ).get_score());
+/// ```
+#[wasm_bindgen(js_name = Bytestat)]
+pub struct WasmBytestat {
+    stats:Bytestat,
+}
+
+#[wasm_bindgen(js_class = Bytestat)]
+impl WasmBytestat {
+  /// Create new analysis.
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> WasmBytestat {
+    WasmBytestat {
+      stats:Bytestat::new(),
+    }
+  }
+
+  /// Analyze the bytes of an array, in order, after the ones already analyzed.
+  #[wasm_bindgen(js_name = analyzeBytes)]
+  pub fn analyze_bytes(&mut self, data:&[u8]) {
+    self.stats.analyze_slice(data);
+  }
+
+  /// Generate the final score, between 0 and 100, see `Bytestat::get_score`.
+  #[wasm_bindgen(js_name = getScore)]
+  pub fn get_score(&self) -> f64 {
+    self.stats.get_score()
+  }
+
+  /// Get the number of bytes analyzed, as a JavaScript number exact up to 2^53.
+  #[wasm_bindgen(js_name = getCounter)]
+  pub fn get_counter(&self) -> f64 {
+    self.stats.get_counter() as f64
+  }
+
+  /// Tell if enough bytes were analyzed for the score to be significant, see `Bytestat::is_significant`.
+  #[wasm_bindgen(js_name = isSignificant)]
+  pub fn is_significant(&self) -> bool {
+    self.stats.is_significant()
+  }
+}
+
+impl Default for WasmBytestat {
+  fn default() -> WasmBytestat {
+    WasmBytestat::new()
+  }
+}
@@ -0,0 +1,244 @@
+//! # Word analysis
+//!
+//! Analysis of a sequence of 16 bits symbols.
+
+use alloc::{vec, vec::Vec};
+use crate::{Scores, Weights};
+
+// Number of possible symbols.
+const BINS:usize = 1 << 16;
+
+// Number of interval slots, intervals of INTERVALS - 1 symbols or more are all recorded as INTERVALS - 1.
+const INTERVALS:usize = 4 * BINS;
+
+/// Running analysis of a sequence of 16 bits symbols, with the `alloc` feature.
+///
+/// The five scores of `Bytestat` are computed over the 65536 possible symbols instead of the 256 byte values:
+/// the distribution scores count the symbols out of 65536, the interval scores reach 1.0 once
+/// 2 * 65536 consecutive intervals are significant.
+/// An interval is significant when it was recorded more than (symbols count) / (16 * 65536) times,
+/// the same proportion as the default significance divisor of `Bytestat` for 256 values.
+/// The counts of 65536 symbols rarely all differ, the uniqueness score stays low unless the set is huge.
+///
+/// The distribution, the last positions and the intervals are allocated on the heap, about 3 MiB.
+///
+/// # Examples
+///
+/// ```
+/// use libbytestat::{Bytestat, WordStat};
+/// let mut words = WordStat::new();
+/// let mut bytes = Bytestat::new();
+/// for x in 0..256u16 {
+///   words.analyze( x );
+///   words.analyze( x );
+///   bytes.analyze( x as u8 );
+///   bytes.analyze( x as u8 );
+/// }
+///
+/// assert_eq!(bytes.get_score_non_zero(), 1.0);
+/// assert_eq!(words.get_score_non_zero(), 256.0 / 65536.0);
+/// words.analyze_slice( &(256..65536u32).map(|x| x as u16).collect::<Vec<u16>>() );
+/// assert_eq!(words.get_score_non_zero(), 1.0);
+/// assert_eq!(words.get_score_unique(), 0.0);
+/// words.analyze( 7 );
+/// assert_eq!(words.get_score_unique(), 1.0 / 65536.0);
+/// ```
+#[derive(Clone)]
+pub struct WordStat {
+    counter:u128,
+    dist:Vec<u128>,
+    interval:Vec<u32>,
+    last:Vec<u128>,
+    weights:Weights,
+}
+
+impl WordStat {
+  /// Create new WordStat object.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use libbytestat::WordStat;
+  /// let stats = WordStat::new();
+  /// ```
+  pub fn new() -> WordStat {
+    WordStat {
+      counter:0,
+      dist:vec![0;BINS],
+      interval:vec![0;INTERVALS],
+      last:vec![0;BINS],
+      weights:Weights::default(),
+    }
+  }
+
+  /// Analyze one symbol, symbols must be analysed in sequence.
+  /// The interval since the previous occurrence of the same symbol is recorded,
+  /// the first occurrence of each symbol has no previous occurrence, no interval is recorded for it.
+  ///
+  /// # Arguments
+  ///
+  /// * `value` - A symbol to be analyzed, u16
+  pub fn analyze(&mut self, value:u16) {
+    self.counter += 1;
+    let last = self.last[value as usize];
+    if last > 0 {
+      let gap = self.counter - last;
+      let slot = if gap >= INTERVALS as u128 { INTERVALS - 1 } else { gap as usize };
+      self.interval[slot] = self.interval[slot].saturating_add(1);
+    }
+    self.dist[value as usize] += 1;
+    self.last[value as usize] = self.counter;
+  }
+
+  /// Analyze a slice of symbols, in order.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The symbols to be analyzed, &[u16]
+  pub fn analyze_slice(&mut self, data:&[u16]) {
+    for value in data {
+      self.analyze(*value);
+    }
+  }
+
+  /// Get the number of symbols analyzed.
+  pub fn get_counter(&self) -> u128 {
+    self.counter
+  }
+
+  /// Generate the score based on distribution of unique symbols being present in the set.
+  ///
+  /// (unique symbols present in set) / (maximum number of possible unique symbols, 65536)
+  pub fn get_score_non_zero(&self) -> f64 {
+    self.get_scores().non_zero
+  }
+
+  /// Generate the score based on the uniqueness of the symbols distribution.
+  ///
+  /// (symbols with a count shared by no other symbol) / 65536
+  pub fn get_score_unique(&self) -> f64 {
+    self.get_scores().unique
+  }
+
+  /// Generate the score based on the amplitude of the symbols distribution, how flat the distribution is.
+  ///
+  /// 1 - (standard deviation of the counts) / (mean count), at least 0.0
+  pub fn get_score_amplitude(&self) -> f64 {
+    self.get_scores().amplitude
+  }
+
+  /// Generate the score based on the continuity of significant intervals.
+  ///
+  /// (significant intervals, up to 131072) / 131072
+  pub fn get_score_interval_continuity(&self) -> f64 {
+    self.get_scores().interval_continuity
+  }
+
+  /// Generate the score based on the amplitude of significant intervals.
+  ///
+  /// (largest significant interval, up to 131072) / 131072
+  pub fn get_score_interval_amplitude(&self) -> f64 {
+    self.get_scores().interval_amplitude
+  }
+
+  /// Generate the final score, between 0 and 100, weighted like the final score of `Bytestat`.
+  pub fn get_score(&self) -> f64 {
+    self.get_scores().total
+  }
+
+  /// Generate all the scores at once, as named fields.
+  pub fn get_scores(&self) -> Scores {
+    let mut scores = Scores {
+      non_zero:0.0,
+      unique:0.0,
+      amplitude:0.0,
+      interval_continuity:0.0,
+      interval_amplitude:0.0,
+      total:0.0,
+    };
+
+    if self.counter == 0 {
+      return scores
+    }
+
+    //1 of 5
+    scores.non_zero = self.dist.iter().filter(|x| **x > 0).count() as f64 / BINS as f64;
+
+    //2 of 5
+    let mut dist_sorted = self.dist.clone();
+    dist_sorted.sort_unstable();
+    let mut dist_unique = 0;
+    for x in 0..BINS {
+      let same_as_previous = x > 0 && dist_sorted[x - 1] == dist_sorted[x];
+      let same_as_next = x < BINS - 1 && dist_sorted[x + 1] == dist_sorted[x];
+      if !same_as_previous && !same_as_next {
+        dist_unique += 1;
+      }
+    }
+    scores.unique = dist_unique as f64 / BINS as f64;
+
+    //3 of 5
+    let squares:u128 = self.dist.iter().map(|x| x * x).sum();
+    let variation = crate::sqrt((BINS as u128 * squares - self.counter * self.counter) as f64) / self.counter as f64;
+    scores.amplitude = if variation < 1.0 { 1.0 - variation } else { 0.0 };
+
+    //4 of 5
+    let threshold = self.counter / (16 * BINS as u128);
+    let mut interval_max = 0;
+    let mut significant = 0;
+    for x in 1..INTERVALS {
+      if self.interval[x] as u128 > threshold {
+        interval_max = x;
+        significant += 1;
+      }
+    }
+    scores.interval_continuity = significant.min(2 * BINS) as f64 / (2 * BINS) as f64;
+
+    //5 of 5
+    scores.interval_amplitude = interval_max.min(2 * BINS) as f64 / (2 * BINS) as f64;
+
+    //FINAL SCORE
+    scores.total = scores.non_zero * self.weights.non_zero;
+    scores.total += scores.unique * self.weights.unique;
+    scores.total += scores.amplitude * self.weights.amplitude;
+    scores.total += scores.interval_continuity * self.weights.interval_continuity;
+    scores.total += scores.interval_amplitude * self.weights.interval_amplitude;
+
+    scores
+  }
+
+  /// Generate all the scores at once, in the order of the fields of `Scores`.
+  pub fn get_scores_array(&self) -> [f64;6] {
+    let scores = self.get_scores();
+    [
+      scores.non_zero,
+      scores.unique,
+      scores.amplitude,
+      scores.interval_continuity,
+      scores.interval_amplitude,
+      scores.total
+    ]
+  }
+}
+
+impl Default for WordStat {
+  fn default() -> WordStat {
+    WordStat::new()
+  }
+}
+
+/// Show the number of symbols analyzed and the scores.
+impl core::fmt::Debug for WordStat {
+  fn fmt(&self, f:&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let scores = self.get_scores();
+    f.debug_struct("WordStat")
+      .field("counter", &self.counter)
+      .field("score_non_zero", &scores.non_zero)
+      .field("score_unique", &scores.unique)
+      .field("score_amplitude", &scores.amplitude)
+      .field("score_interval_continuity", &scores.interval_continuity)
+      .field("score_interval_amplitude", &scores.interval_amplitude)
+      .field("score", &scores.total)
+      .finish()
+  }
+}
@@ -0,0 +1,21 @@
+//! # WebAssembly smoke test
+//!
+//! Run with `cargo test --target wasm32-unknown-unknown --features wasm --test wasm`,
+//! with `wasm-bindgen-test-runner` as the runner of the target.
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use libbytestat::{Bytestat, WasmBytestat};
+
+#[wasm_bindgen_test]
+fn analyze_bytes() {
+  let data:Vec<u8> = (0..100000u32).map(|x| (x * 7 % 256) as u8).collect();
+  let mut stats = WasmBytestat::new();
+  stats.analyze_bytes(&data[..50000]);
+  stats.analyze_bytes(&data[50000..]);
+
+  assert_eq!(stats.get_counter(), 100000.0);
+  assert_eq!(stats.get_score(), Bytestat::from_slice(&data).get_score());
+  assert!(!stats.is_significant());
+}